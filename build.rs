@@ -0,0 +1,87 @@
+use std::{
+    collections::HashSet,
+    env,
+    fmt::Write,
+    fs::{read_dir, read_to_string, write},
+    path::Path,
+};
+
+// the tests read the answers themselves
+#[allow(dead_code)]
+#[path = "src/answer_entry.rs"]
+mod answer_entry;
+
+use answer_entry::AnswerEntry;
+
+/// Generates `answer_tests_NN.rs` in `OUT_DIR` for every `src/NN` directory,
+/// with one `#[test]` per entry of its `answers.txt`, see the `answer_tests!` macro.
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    println!("cargo::rerun-if-changed=src");
+
+    for entry in read_dir("src").unwrap() {
+        let path = entry.unwrap().path();
+
+        let Some(day) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        if !path.is_dir() || day.parse::<u8>().is_err() {
+            continue;
+        }
+
+        let tests = generate_tests(day, &path.join("answers.txt"));
+
+        write(
+            Path::new(&out_dir).join(format!("answer_tests_{}.rs", day)),
+            tests,
+        )
+        .unwrap();
+    }
+}
+
+fn generate_tests(day: &str, answers_file: &Path) -> String {
+    let mut tests = String::new();
+    let mut names = HashSet::new();
+
+    let Ok(answers) = read_to_string(answers_file) else {
+        return tests;
+    };
+
+    for line in answers.lines() {
+        // malformed lines are reported by `aoc run` and by the tests of the other entries
+        let Ok(Some(AnswerEntry {
+            file_name, part, ..
+        })) = AnswerEntry::parse(line)
+        else {
+            continue;
+        };
+
+        let name: String = file_name
+            .trim_end_matches(".txt")
+            .chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() {
+                    char
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        // so are duplicates, which would not compile as tests of the same name
+        if !names.insert((name.clone(), part)) {
+            continue;
+        }
+
+        writeln!(
+            tests,
+            "#[test]\nfn answer_{}_part_{}() {{\n    run_answer_test(\"src/{}/{}\", \"{}\");\n}}\n",
+            name, part, day, file_name, part
+        )
+        .unwrap();
+    }
+
+    tests
+}
@@ -0,0 +1,71 @@
+use aoc_2024::{Context, InputParser, ParseError, ProblemSolver, Registry, Runner, Source};
+use regex::Regex;
+use std::{collections::HashMap, iter::zip};
+
+type Input = (Vec<u32>, Vec<u32>);
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        let regex = Regex::new(r"^([0-9]+)\s+([0-9]+)$").unwrap();
+
+        let mut left_vec = Vec::<u32>::new();
+        let mut right_vec = Vec::<u32>::new();
+
+        for line in source.lines() {
+            let captures = regex
+                .captures(line)
+                .ok_or_else(|| source.error(line, "expected two numbers"))?;
+
+            left_vec.push(source.parse(captures.get(1).unwrap().as_str())?);
+            right_vec.push(source.parse(captures.get(2).unwrap().as_str())?);
+        }
+
+        Ok((left_vec, right_vec))
+    }
+}
+
+type Output = u32;
+
+struct Solver;
+
+impl ProblemSolver<Input, Output> for Solver {
+    fn solve_part_one(&self, input: &Input, _context: &Context) -> Output {
+        let mut left = input.0.clone();
+        let mut right = input.1.clone();
+
+        left.sort();
+        right.sort();
+
+        zip(left.iter(), right.iter())
+            .map(|(left, right)| left.abs_diff(*right))
+            .sum()
+    }
+
+    fn solve_part_two(&self, input: &Input, _context: &Context) -> Output {
+        let counts = input
+            .1
+            .iter()
+            .fold(HashMap::<u32, u32>::new(), |mut counts, value| {
+                match counts.get(value) {
+                    Some(count) => counts.insert(*value, count + 1),
+                    None => counts.insert(*value, 1),
+                };
+
+                counts
+            });
+
+        input
+            .0
+            .iter()
+            .map(|value| value * counts.get(value).unwrap_or(&0))
+            .sum()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(1, Parser, Solver));
+}
+
+aoc_2024::answer_tests!("01", Parser, Solver);
@@ -0,0 +1,129 @@
+use std::ops::RangeInclusive;
+
+use aoc_2024::{
+    Context, InputParser, ParamSpec, ParseError, ProblemSolver, Registry, Runner, Source,
+};
+
+type Input = Vec<Vec<i32>>;
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> std::result::Result<Input, ParseError> {
+        source
+            .lines()
+            .map(|line| {
+                let sequence = line
+                    .split_ascii_whitespace()
+                    .map(|num_str| source.parse::<i32>(num_str))
+                    .collect::<std::result::Result<Vec<i32>, ParseError>>()?;
+
+                if sequence.is_empty() {
+                    return Err(source.error(line, "expected at least one level"));
+                }
+
+                Ok(sequence)
+            })
+            .collect()
+    }
+}
+
+type Result = u32;
+
+struct Solver;
+
+enum LineResult {
+    Valid,
+    Invalid { index: usize },
+}
+
+const MIN_STEP: i32 = 1;
+const MAX_STEP: i32 = 3;
+
+// the allowed difference between neighbouring levels
+fn step_range(context: &Context) -> RangeInclusive<i32> {
+    let param = |name, default| context.params().unsigned_as(name).unwrap_or(default);
+
+    param("min_step", MIN_STEP)..=param("max_step", MAX_STEP)
+}
+
+fn validate_sequence<T: Iterator<Item = i32>>(
+    mut iter: T,
+    steps: &RangeInclusive<i32>,
+) -> LineResult {
+    let mut prev = iter.next().unwrap();
+
+    for (index, value) in iter.enumerate() {
+        let difference = value - prev;
+
+        if !steps.contains(&difference) {
+            return LineResult::Invalid { index };
+        }
+
+        prev = value;
+    }
+
+    LineResult::Valid
+}
+
+impl ProblemSolver<Input, Result> for Solver {
+    fn solve_part_one(&self, input: &Input, context: &Context) -> Result {
+        let steps = step_range(context);
+
+        input
+            .iter()
+            .filter(|sequence| {
+                [-1, 1].into_iter().any(|invert_coef| {
+                    let iter = sequence.iter().map(|value| value * invert_coef);
+
+                    matches!(validate_sequence(iter, &steps), LineResult::Valid)
+                })
+            })
+            .count() as Result
+    }
+
+    fn solve_part_two(&self, input: &Input, context: &Context) -> Result {
+        let steps = step_range(context);
+
+        input
+            .iter()
+            .map(|sequence| {
+                for invert_coef in [-1, 1] {
+                    let iter = sequence.iter().map(|value| value * invert_coef);
+
+                    match validate_sequence(iter.clone(), &steps) {
+                        LineResult::Invalid { index } => {
+                            for index_to_remove in [index, index + 1] {
+                                let head = iter.clone().take(index_to_remove);
+                                let tail = iter.clone().skip(index_to_remove + 1);
+
+                                let repaired_sequence = head.chain(tail);
+
+                                match validate_sequence(repaired_sequence, &steps) {
+                                    LineResult::Invalid { index: _ } => continue,
+                                    LineResult::Valid => return 1,
+                                }
+                            }
+                        }
+                        LineResult::Valid => return 1,
+                    }
+                }
+
+                0
+            })
+            .sum()
+    }
+
+    fn params(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::unsigned("min_step", Some(MIN_STEP as u64)).fitting::<i32>(),
+            ParamSpec::unsigned("max_step", Some(MAX_STEP as u64)).fitting::<i32>(),
+        ]
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(2, Parser, Solver));
+}
+
+aoc_2024::answer_tests!("02", Parser, Solver);
@@ -1,6 +1,5 @@
-use aoc_2024::{InputParser, ProblemSolver, Runner};
+use aoc_2024::{Context, InputParser, ParseError, ProblemSolver, Registry, Runner, Source};
 use regex::Regex;
-use std::fs::read_to_string;
 
 enum Instruction {
     Mul(u32, u32),
@@ -13,33 +12,34 @@ type Input = Vec<Instruction>;
 struct Parser;
 
 impl InputParser<Input> for Parser {
-    fn parse(&self, filename: &str) -> Input {
-        let file = read_to_string(filename).unwrap();
-
+    fn parse(&self, source: &Source) -> std::result::Result<Input, ParseError> {
         let regex = Regex::new(
             r"(?<mul>mul\((?<left>[0-9]{1,3}),(?<right>[0-9]{1,3})\))|(?<dont>don't\(\))|(?<do>do\(\))",
         )
         .unwrap();
 
         regex
-            .captures_iter(file.as_str())
+            .captures_iter(source.text())
             .map(|captures| {
-                if let Some(_) = captures.name("mul") {
+                if captures.name("mul").is_some() {
                     let left = captures.name("left").unwrap().as_str();
                     let right = captures.name("right").unwrap().as_str();
 
-                    return Instruction::Mul(left.parse().unwrap(), right.parse().unwrap());
+                    return Ok(Instruction::Mul(source.parse(left)?, source.parse(right)?));
                 }
 
-                if let Some(_) = captures.name("dont") {
-                    return Instruction::Dont;
+                if captures.name("dont").is_some() {
+                    return Ok(Instruction::Dont);
                 }
 
-                if let Some(_) = captures.name("do") {
-                    return Instruction::Do;
+                if captures.name("do").is_some() {
+                    return Ok(Instruction::Do);
                 }
 
-                panic!("Matched unknown instruction!")
+                Err(source.error(
+                    captures.get(0).unwrap().as_str(),
+                    "matched unknown instruction",
+                ))
             })
             .collect()
     }
@@ -89,7 +89,17 @@ impl Interpreter {
 struct Solver;
 
 impl ProblemSolver<Input, Result> for Solver {
-    fn solve(&self, input: Input) -> Result {
+    fn solve_part_one(&self, input: &Input, _context: &Context) -> Result {
+        input
+            .iter()
+            .map(|instr| match instr {
+                Instruction::Mul(left, right) => left * right,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn solve_part_two(&self, input: &Input, _context: &Context) -> Result {
         input
             .iter()
             .fold(Interpreter::new(), |interpreter, instr| match instr {
@@ -101,10 +111,8 @@ impl ProblemSolver<Input, Result> for Solver {
     }
 }
 
-fn main() {
-    Runner::new(Parser, Solver).run(&vec![
-        "src/03/input_1.txt",
-        "src/03/input_2.txt",
-        "src/03/input_3.txt",
-    ]);
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(3, Parser, Solver));
 }
+
+aoc_2024::answer_tests!("03", Parser, Solver);
@@ -0,0 +1,156 @@
+use aoc_2024::{
+    Context, Direction, Grid, InputAssumption, InputParser, ParamSpec, ParseError, Position,
+    ProblemSolver, Registry, Runner, Source,
+};
+use std::str::Chars;
+
+type Input = Grid<char>;
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> std::result::Result<Input, ParseError> {
+        Grid::parse(source, |_, char| Ok(char))
+    }
+
+    fn assumptions(&self) -> Vec<InputAssumption> {
+        vec![InputAssumption::Rectangular]
+    }
+}
+
+const XMAS_SEARCH_STR: &str = "XMAS";
+
+const POSSIBLE_DIRECTIONS: [[Direction; 2]; 4] = [
+    [Direction::DownRight, Direction::DownLeft],
+    [Direction::DownLeft, Direction::UpLeft],
+    [Direction::UpLeft, Direction::UpRight],
+    [Direction::UpRight, Direction::DownRight],
+];
+
+const SEARCH_STR: &str = "MAS";
+
+trait SearchInDirection {
+    fn search_in_direction(
+        &self,
+        direction: Direction,
+        search_chars: Chars,
+        from: Position,
+    ) -> bool;
+}
+
+impl SearchInDirection for Input {
+    fn search_in_direction(
+        &self,
+        direction: Direction,
+        mut search_chars: Chars,
+        position: Position,
+    ) -> bool {
+        match search_chars.next() {
+            None => true,
+            Some(curr_search_char) => {
+                if self.get(position) != Some(&curr_search_char) {
+                    return false;
+                }
+
+                match self.step(position, direction) {
+                    Some(next_position) => {
+                        self.search_in_direction(direction, search_chars, next_position)
+                    }
+                    // the word ends at the edge of the grid
+                    None => search_chars.next().is_none(),
+                }
+            }
+        }
+    }
+}
+
+type Result = u32;
+
+fn check_word(word: &str) -> std::result::Result<(), &'static str> {
+    if word.is_empty() {
+        return Err("the word is empty");
+    }
+
+    Ok(())
+}
+
+// the two words of part 2 cross at their middle letter
+fn check_cross_word(word: &str) -> std::result::Result<(), &'static str> {
+    check_word(word)?;
+
+    if word.chars().count().is_multiple_of(2) {
+        return Err("the word has no middle letter to cross at, its length must be odd");
+    }
+
+    Ok(())
+}
+
+struct Solver;
+
+impl ProblemSolver<Input, Result> for Solver {
+    fn solve_part_one(&self, input: &Input, context: &Context) -> Result {
+        let word = context.params().text("word").unwrap_or(XMAS_SEARCH_STR);
+
+        input
+            .positions()
+            .map(|position| -> u32 {
+                Direction::ALL
+                    .iter()
+                    .filter(|direction| {
+                        input.search_in_direction(**direction, word.chars(), position)
+                    })
+                    .count() as u32
+            })
+            .sum()
+    }
+
+    fn solve_part_two(&self, input: &Input, context: &Context) -> Result {
+        let word = context.params().text("cross_word").unwrap_or(SEARCH_STR);
+        // the words cross at their middle character
+        let offset = (word.chars().count() / 2) as isize;
+
+        input
+            .positions()
+            .map(|position| -> u32 {
+                POSSIBLE_DIRECTIONS
+                    .iter()
+                    .map(|directions| {
+                        directions.iter().all(|direction| {
+                            input
+                                .step(position, direction.reverse().offset() * offset)
+                                .is_some_and(|start| {
+                                    input.search_in_direction(*direction, word.chars(), start)
+                                })
+                        }) as u32
+                    })
+                    .sum()
+            })
+            .sum()
+    }
+
+    fn params(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::text("word", Some(XMAS_SEARCH_STR)).checked(check_word),
+            ParamSpec::text("cross_word", Some(SEARCH_STR)).checked(check_cross_word),
+        ]
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(4, Parser, Solver));
+}
+
+#[test]
+fn test_word_params() {
+    let resolve = |arg: &str| aoc_2024::Params::resolve(&Solver.params(), &[arg.parse().unwrap()]);
+
+    assert!(resolve("word=").unwrap_err().to_string() == "--param word=: the word is empty");
+    assert!(
+        resolve("cross_word=XMAS").unwrap_err().to_string()
+            == "--param cross_word=XMAS: the word has no middle letter to cross at, its length must be odd"
+    );
+    assert!(resolve("word=XMAS").is_ok());
+    assert!(resolve("cross_word=SAMAS").is_ok());
+}
+
+aoc_2024::answer_tests!("04", Parser, Solver);
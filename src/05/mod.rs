@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_2024::{
+    Context, InputAssumption, InputParser, ParseError, ProblemSolver, Registry, Runner, Source,
+};
+
+type Deps = Vec<(u32, u32)>;
+
+type Updates = Vec<Vec<u32>>;
+
+struct Input {
+    deps: Deps,
+    updates: Updates,
+}
+
+type Page = u32;
+
+type DependencyMap = HashMap<Page, HashSet<Page>>;
+
+impl Input {
+    fn get_dependency_map(&self) -> DependencyMap {
+        self.deps
+            .iter()
+            .fold(DependencyMap::new(), |mut deps, (depends_on, dependant)| {
+                match deps.get_mut(dependant) {
+                    None => {
+                        let mut value_deps = HashSet::new();
+                        value_deps.insert(*depends_on);
+                        deps.insert(*dependant, value_deps);
+                    }
+                    Some(value_deps) => {
+                        value_deps.insert(*depends_on);
+                    }
+                }
+
+                deps
+            })
+    }
+}
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> std::result::Result<Input, ParseError> {
+        let lines = source.lines();
+
+        let deps: Deps = lines
+            .clone()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                let (depends_on, dependant) = source.split_once(line, "|")?;
+
+                Ok((source.parse(depends_on)?, source.parse(dependant)?))
+            })
+            .collect::<std::result::Result<Deps, ParseError>>()?;
+
+        let updates: Updates = lines
+            .skip(deps.len() + 1)
+            .map(|line| line.split(',').map(|num| source.parse(num)).collect())
+            .collect::<std::result::Result<Updates, ParseError>>()?;
+
+        Ok(Input { deps, updates })
+    }
+
+    fn assumptions(&self) -> Vec<InputAssumption> {
+        // the ordering rules, then the updates
+        vec![InputAssumption::Sections(2)]
+    }
+}
+
+type Result = u32;
+
+struct TopSorter<'a, 'b> {
+    dependency_map: &'a DependencyMap,
+    update: &'b [Page],
+    visiting: HashSet<Page>,
+    topological_order: Vec<Page>,
+}
+
+impl<'a, 'b> TopSorter<'a, 'b> {
+    fn new(dependency_map: &'a DependencyMap, update: &'b [Page]) -> Self {
+        Self {
+            dependency_map,
+            update,
+            visiting: HashSet::new(),
+            topological_order: Vec::new(),
+        }
+    }
+
+    fn top_sort(mut self) -> Vec<Page> {
+        // walking the update in its own order keeps the result deterministic
+        for page_in_update in self.update {
+            self.visit(*page_in_update);
+
+            if self.update.len() == self.topological_order.len() {
+                break;
+            }
+        }
+
+        self.topological_order
+    }
+
+    fn visit(&mut self, page: Page) {
+        // a page that is still being visited depends on itself through a cycle in the rules,
+        // there is no correct order then so we just break the cycle here
+        if self.topological_order.contains(&page) || !self.visiting.insert(page) {
+            return;
+        }
+
+        if let Some(page_deps) = self.dependency_map.get(&page) {
+            for page_dep in self.update {
+                if page_deps.contains(page_dep) {
+                    self.visit(*page_dep);
+                }
+            }
+        }
+
+        self.topological_order.push(page);
+    }
+}
+
+fn is_correctly_ordered(dependency_map: &DependencyMap, update: &[Page]) -> bool {
+    let empty_set = HashSet::new();
+
+    let pages_in_update: HashSet<Page> = update.iter().copied().collect();
+
+    let mut updated = HashSet::<Page>::new();
+
+    update.iter().all(|page_in_update| {
+        let deps = dependency_map.get(page_in_update).unwrap_or(&empty_set);
+
+        let deps_printed = deps
+            .intersection(&pages_in_update)
+            .all(|page_dep| updated.contains(page_dep));
+
+        updated.insert(*page_in_update);
+
+        deps_printed
+    })
+}
+
+fn middle_page(update: &[Page]) -> Page {
+    update[update.len() / 2]
+}
+
+struct Solver;
+
+impl ProblemSolver<Input, Result> for Solver {
+    fn solve_part_one(&self, input: &Input, _context: &Context) -> Result {
+        let dependency_map = input.get_dependency_map();
+
+        input
+            .updates
+            .iter()
+            .filter(|update| is_correctly_ordered(&dependency_map, update))
+            .map(|update| middle_page(update))
+            .sum()
+    }
+
+    fn solve_part_two(&self, input: &Input, _context: &Context) -> Result {
+        let dependency_map = input.get_dependency_map();
+
+        input
+            .updates
+            .iter()
+            .filter(|update| !is_correctly_ordered(&dependency_map, update))
+            .map(|update| middle_page(&TopSorter::new(&dependency_map, update).top_sort()))
+            .sum()
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(5, Parser, Solver));
+}
+
+aoc_2024::answer_tests!("05", Parser, Solver);
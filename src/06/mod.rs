@@ -0,0 +1,346 @@
+use aoc_2024::{
+    Canvas, Color, Context, Direction, Grid, InputAssumption, InputParser, ParseError, Part,
+    Position, ProblemSolver, Registry, Runner, Source,
+};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Space,
+    Obstacle,
+}
+
+#[derive(Clone)]
+struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl Map {
+    fn get_tile(&self, position: &Position) -> Tile {
+        self.tiles[*position]
+    }
+
+    // none when the move leaves the map
+    fn move_in_dir(&self, position: &Position, dir: Direction) -> Option<Position> {
+        self.tiles.step(*position, dir)
+    }
+
+    fn intersects_obstacle(&self, from: Position, dir: Direction) -> bool {
+        let mut curr_pos = Some(from);
+
+        while let Some(pos) = curr_pos {
+            if self.get_tile(&pos) == Tile::Obstacle {
+                return true;
+            }
+
+            curr_pos = self.move_in_dir(&pos, dir);
+        }
+
+        false
+    }
+
+    fn replace_tile(&self, at: &Position, tile: Tile) -> Map {
+        let mut new_map = self.clone();
+
+        new_map.tiles[*at] = tile;
+
+        new_map
+    }
+}
+
+struct Input {
+    map: Map,
+    starting_pos: Position,
+    starting_dir: Direction,
+}
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        let mut guard: Option<(Position, Direction)> = None;
+
+        let tiles = Grid::parse(source, |position, char| match char {
+            '.' => Ok(Tile::Space),
+            '#' => Ok(Tile::Obstacle),
+            _ => {
+                let dir = Direction::from_char(char).ok_or("unknown tile")?;
+
+                if guard.is_some() {
+                    return Err("there is already a guard on the map");
+                }
+
+                guard = Some((position, dir));
+
+                Ok(Tile::Space)
+            }
+        })?;
+
+        let Some((starting_pos, starting_dir)) = guard else {
+            return Err(source.error(source.first_line()?, "there is no guard on the map"));
+        };
+
+        Ok(Input {
+            starting_pos,
+            starting_dir,
+            map: Map { tiles },
+        })
+    }
+
+    fn assumptions(&self) -> Vec<InputAssumption> {
+        vec![
+            InputAssumption::Rectangular,
+            InputAssumption::Chars(".#^>v<"),
+        ]
+    }
+}
+
+enum RunEndReason {
+    OutOfBounds,
+    Cycle,
+}
+
+struct RunResult {
+    end_reason: RunEndReason,
+    visited_pos_with_dirs: HashMap<Position, Vec<Direction>>,
+}
+
+fn run(
+    map: &Map,
+    starting_pos: &Position,
+    starting_dir: Direction,
+    context: &Context,
+) -> RunResult {
+    walk(map, starting_pos, starting_dir, context, |_, _| {})
+}
+
+// like `run`, but passes every position the guard is at along with its direction to `step`
+fn walk(
+    map: &Map,
+    starting_pos: &Position,
+    starting_dir: Direction,
+    context: &Context,
+    mut step: impl FnMut(Position, Direction),
+) -> RunResult {
+    let mut pos = *starting_pos;
+    let mut dir = starting_dir;
+
+    let mut visited_pos_with_dirs = HashMap::<Position, Vec<Direction>>::new();
+
+    loop {
+        match visited_pos_with_dirs.get_mut(&pos) {
+            Some(dirs) => {
+                if dirs.contains(&dir) {
+                    context.trace(|| format!("cycle at {},{}", pos.x, pos.y));
+
+                    return RunResult {
+                        end_reason: RunEndReason::Cycle,
+                        visited_pos_with_dirs,
+                    };
+                }
+
+                dirs.push(dir);
+            }
+            None => {
+                let dirs = vec![dir];
+                visited_pos_with_dirs.insert(pos, dirs);
+            }
+        }
+
+        step(pos, dir);
+
+        let Some(next_pos) = map.move_in_dir(&pos, dir) else {
+            context.trace(|| format!("left the map at {},{}", pos.x, pos.y));
+
+            return RunResult {
+                end_reason: RunEndReason::OutOfBounds,
+                visited_pos_with_dirs,
+            };
+        };
+
+        match map.get_tile(&next_pos) {
+            Tile::Space => pos = next_pos,
+            Tile::Obstacle => {
+                dir = dir.rotate_right();
+
+                context.trace(|| format!("turned at {},{}", pos.x, pos.y));
+            }
+        }
+    }
+}
+
+// positions in front of the guard where an obstacle would turn it onto a path it has walked before
+fn candidate_obstacles(input: &Input, context: &Context) -> Vec<Position> {
+    let run_without_obstacle = run(&input.map, &input.starting_pos, input.starting_dir, context);
+
+    let mut possible_positions = run_without_obstacle
+        .visited_pos_with_dirs
+        .iter()
+        .flat_map(|(pos, dirs)| {
+            dirs.iter().filter_map(|dir| {
+                let next_pos = input.map.move_in_dir(pos, *dir)?;
+
+                if input.map.get_tile(&next_pos) == Tile::Space
+                    && input.map.intersects_obstacle(*pos, dir.rotate_right())
+                {
+                    return Some(next_pos);
+                }
+
+                None
+            })
+        })
+        .collect::<Vec<Position>>();
+
+    possible_positions.sort();
+    possible_positions.dedup();
+
+    context.info(|| format!("{} candidate obstacles", possible_positions.len()));
+
+    possible_positions
+}
+
+fn makes_cycle(input: &Input, pos: &Position, context: &Context) -> bool {
+    let map = input.map.replace_tile(pos, Tile::Obstacle);
+
+    let is_cycle = matches!(
+        run(&map, &input.starting_pos, input.starting_dir, context).end_reason,
+        RunEndReason::Cycle
+    );
+
+    context.debug(|| {
+        format!(
+            "obstacle at {},{} makes a cycle: {}",
+            pos.x, pos.y, is_cycle
+        )
+    });
+
+    is_cycle
+}
+
+fn map_canvas(map: &Map) -> Canvas {
+    Canvas::new(&map.tiles, |tile| match tile {
+        Tile::Space => '.',
+        Tile::Obstacle => '#',
+    })
+}
+
+struct Solver;
+
+type Output = usize;
+
+impl ProblemSolver<Input, Output> for Solver {
+    fn solve_part_one(&self, input: &Input, context: &Context) -> Output {
+        run(&input.map, &input.starting_pos, input.starting_dir, context)
+            .visited_pos_with_dirs
+            .len()
+    }
+
+    fn solve_part_two(&self, input: &Input, context: &Context) -> Output {
+        candidate_obstacles(input, context)
+            .iter()
+            // each candidate is a full simulation, so stop trying them once cancelled
+            .take_while(|_| !context.is_cancelled())
+            .filter(|pos| makes_cycle(input, pos, context))
+            .count()
+    }
+
+    // the walked path, and for part 2 the candidate obstacles with those making a cycle as `O`
+    fn render(&self, input: &Input, context: &Context) -> Option<Canvas> {
+        let mut canvas = map_canvas(&input.map);
+
+        let visited_pos_with_dirs =
+            run(&input.map, &input.starting_pos, input.starting_dir, context).visited_pos_with_dirs;
+
+        canvas.path(
+            visited_pos_with_dirs
+                .iter()
+                .flat_map(|(pos, dirs)| dirs.iter().map(move |dir| (*pos, *dir))),
+            Color::Yellow,
+        );
+
+        if context.part() == Part::Two {
+            for pos in candidate_obstacles(input, context) {
+                if makes_cycle(input, &pos, context) {
+                    canvas.mark(pos, 'O', Color::Red);
+                } else {
+                    canvas.mark(pos, 'o', Color::Blue);
+                }
+            }
+        }
+
+        canvas.mark(
+            input.starting_pos,
+            input.starting_dir.to_char(),
+            Color::Green,
+        );
+
+        Some(canvas)
+    }
+
+    // a frame for every step of the guard, and for part 2 one for every candidate obstacle tried
+    fn animate(
+        &self,
+        input: &Input,
+        context: &Context,
+        frame: &mut dyn FnMut(&Canvas),
+    ) -> Option<Canvas> {
+        let mut canvas = map_canvas(&input.map);
+
+        walk(
+            &input.map,
+            &input.starting_pos,
+            input.starting_dir,
+            context,
+            |pos, dir| {
+                canvas.path([(pos, dir)], Color::Yellow);
+
+                // the guard hides the path below it until it moves on
+                let path = canvas.cells()[pos];
+
+                canvas.mark(pos, dir.to_char(), Color::Green);
+                frame(&canvas);
+                canvas.set(pos, path);
+            },
+        );
+
+        if context.part() == Part::Two {
+            for pos in candidate_obstacles(input, context) {
+                if makes_cycle(input, &pos, context) {
+                    canvas.mark(pos, 'O', Color::Red);
+                } else {
+                    canvas.mark(pos, 'o', Color::Blue);
+                }
+
+                frame(&canvas);
+            }
+        }
+
+        Some(canvas)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(6, Parser, Solver));
+}
+
+#[test]
+fn test_parse_guards() {
+    let none = Parser.parse(&Source::new("none", "..\n.#\n"));
+    let two = Parser.parse(&Source::new("two", ".^\n>#\n"));
+
+    assert!(
+        none.err().map(|error| error.to_string())
+            == Some(String::from(
+                "none:1:1: there is no guard on the map (found \"..\")"
+            ))
+    );
+    assert!(
+        two.err().map(|error| error.to_string())
+            == Some(String::from(
+                "two:2:1: there is already a guard on the map (found \">\")"
+            ))
+    );
+    assert!(Parser.parse(&Source::new("one", ".^\n.#\n")).is_ok());
+}
+
+aoc_2024::answer_tests!("06", Parser, Solver);
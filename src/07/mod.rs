@@ -0,0 +1,128 @@
+use aoc_2024::{Context, InputParser, ParseError, ProblemSolver, Registry, Runner, Source};
+
+type Int = u64;
+
+struct Equation {
+    result: Int,
+    operands: Vec<Int>,
+}
+
+type Input = Vec<Equation>;
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        source
+            .lines()
+            .map(|line| {
+                let (result_str, operands_str) = source.split_once(line, ":")?;
+
+                let operands = operands_str
+                    .split_whitespace()
+                    .map(|operand_str| source.parse(operand_str))
+                    .collect::<Result<Vec<Int>, ParseError>>()?;
+
+                if operands.is_empty() {
+                    return Err(source.error(operands_str, "expected at least one operand"));
+                }
+
+                Ok(Equation {
+                    result: source.parse(result_str)?,
+                    operands,
+                })
+            })
+            .collect()
+    }
+}
+
+type Output = Int;
+
+struct Solver;
+
+fn glue(left: Int, right: Int) -> Int {
+    let right_digits = right.ilog10() + 1;
+
+    left * 10_u64.pow(right_digits) + right
+}
+
+#[test]
+fn test_glue() {
+    assert!(glue(12, 345) == 12345)
+}
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+    Glue,
+}
+
+impl Operator {
+    fn apply(&self, left: Int, right: Int) -> Int {
+        match self {
+            Operator::Add => left + right,
+            Operator::Multiply => left * right,
+            Operator::Glue => glue(left, right),
+        }
+    }
+}
+
+fn is_calculable<'a, TIter: Iterator<Item = &'a Int> + Clone>(
+    acc: Int,
+    result: Int,
+    mut operands: TIter,
+    operators: &[Operator],
+) -> bool {
+    match operands.next() {
+        None => acc == result,
+        Some(operand) => operators
+            .iter()
+            .map(|operator| operator.apply(acc, *operand))
+            .any(|next_acc| {
+                next_acc <= result && is_calculable(next_acc, result, operands.clone(), operators)
+            }),
+    }
+}
+
+fn total_calibration_result(input: &Input, operators: &[Operator], context: &Context) -> Output {
+    input
+        .iter()
+        // the search is exponential in the number of operands, so check between equations
+        .take_while(|_| !context.is_cancelled())
+        .map(|equation| {
+            let mut operands_iter = equation.operands.iter();
+
+            if is_calculable(
+                *operands_iter.next().unwrap(),
+                equation.result,
+                operands_iter,
+                operators,
+            ) {
+                return equation.result;
+            };
+
+            0
+        })
+        .sum()
+}
+
+impl ProblemSolver<Input, Output> for Solver {
+    fn solve_part_one(&self, input: &Input, context: &Context) -> Output {
+        total_calibration_result(input, &[Operator::Multiply, Operator::Add], context)
+    }
+
+    fn solve_part_two(&self, input: &Input, context: &Context) -> Output {
+        total_calibration_result(
+            input,
+            &[Operator::Glue, Operator::Multiply, Operator::Add],
+            context,
+        )
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(7, Parser, Solver));
+}
+
+aoc_2024::answer_tests!("07", Parser, Solver);
@@ -0,0 +1,154 @@
+use std::{collections::BTreeSet, iter};
+
+use aoc_2024::{
+    Canvas, Color, Context, Grid, InputAssumption, InputParser, Offset, ParseError, Part, Position,
+    ProblemSolver, Registry, Runner, Source,
+};
+
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 {
+        return a;
+    }
+
+    gcd(b, a % b)
+}
+
+#[test]
+fn test_gcd() {
+    assert!(gcd(42, 56) == 14);
+    assert!(gcd(56, 42) == 14);
+}
+
+fn minimize(vector: Offset) -> Offset {
+    let gcd = gcd(vector.x, vector.y);
+
+    Offset::new(vector.x / gcd, vector.y / gcd)
+}
+
+type Frequency = u8;
+
+type Antenas = Vec<Vec<Position>>;
+
+struct Input {
+    // since there are only less than 255 possible frequencies, we can just use a vec to store the more effectively than by using a hashmap
+    // I wanted to use a slice to avoid heap allocations but rust wont let me do that easily
+    antenas: Antenas,
+    map: Grid<Option<Frequency>>,
+}
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        let mut antenas: Antenas = vec![Vec::new(); Frequency::MAX as usize];
+
+        let map = Grid::parse(source, |position, char| {
+            if char == '.' {
+                return Ok(None);
+            }
+
+            antenas
+                .get_mut(char as usize)
+                .ok_or("unknown frequency")?
+                .push(position);
+
+            Ok(Some(char as Frequency))
+        })?;
+
+        Ok(Input { antenas, map })
+    }
+
+    fn assumptions(&self) -> Vec<InputAssumption> {
+        vec![InputAssumption::Rectangular, InputAssumption::Ascii]
+    }
+}
+
+type Output = usize;
+
+struct Solver;
+
+fn antena_pairs(input: &Input) -> impl Iterator<Item = (&Position, &Position)> {
+    input.antenas.iter().flat_map(|antena_positions| {
+        antena_positions
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, position_a)| {
+                antena_positions
+                    .iter()
+                    .skip(idx + 1)
+                    .map(move |position_b| (position_a, position_b))
+            })
+    })
+}
+
+fn antinodes_part_one(input: &Input) -> BTreeSet<Position> {
+    antena_pairs(input)
+        .flat_map(|(position_a, position_b)| {
+            let vector_to_b = position_a.offset_to(*position_b);
+
+            [
+                input.map.step(*position_b, vector_to_b),
+                input.map.step(*position_a, -vector_to_b),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+fn antinodes_part_two(input: &Input) -> BTreeSet<Position> {
+    antena_pairs(input)
+        .flat_map(|(position_a, position_b)| {
+            let vector_to_b = minimize(position_a.offset_to(*position_b));
+
+            [-vector_to_b, vector_to_b].into_iter().flat_map(|vector| {
+                iter::successors(Some(*position_b), move |position| {
+                    input.map.step(*position, vector)
+                })
+            })
+        })
+        .collect()
+}
+
+impl ProblemSolver<Input, Output> for Solver {
+    fn solve_part_one(&self, input: &Input, _context: &Context) -> Output {
+        antinodes_part_one(input).len()
+    }
+
+    fn solve_part_two(&self, input: &Input, _context: &Context) -> Output {
+        antinodes_part_two(input).len()
+    }
+
+    // every frequency in its own color, antinodes as `#` or tinted where there is an antena
+    fn render(&self, input: &Input, context: &Context) -> Option<Canvas> {
+        let mut canvas = Canvas::new(&input.map, |frequency| match frequency {
+            Some(frequency) => *frequency as char,
+            None => '.',
+        });
+
+        let antinodes = match context.part() {
+            Part::One => antinodes_part_one(input),
+            Part::Two => antinodes_part_two(input),
+        };
+
+        for position in antinodes {
+            match input.map[position] {
+                Some(_) => canvas.tint(position, Color::Red),
+                None => canvas.mark(position, '#', Color::Red),
+            }
+        }
+
+        for (position, frequency) in input.map.iter() {
+            if let Some(frequency) = frequency {
+                canvas.color(position, Color::nth(*frequency as usize));
+            }
+        }
+
+        Some(canvas)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(8, Parser, Solver));
+}
+
+aoc_2024::answer_tests!("08", Parser, Solver);
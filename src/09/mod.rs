@@ -0,0 +1,344 @@
+use std::iter;
+
+use aoc_2024::{
+    Canvas, Cell, Color, Context, Grid, InputParser, ParseError, Part, Position, ProblemSolver,
+    Registry, Runner, Source,
+};
+
+type FileId = u32;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Empty,
+    File(FileId),
+}
+
+type Blocks = Vec<Block>;
+
+struct Input {
+    blocks: Vec<Block>,
+    last_file_id: u32,
+}
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        let disk_map = source.text().trim_end();
+
+        let mut file_id = 0;
+        let mut blocks = Blocks::new();
+
+        for (idx, (char_idx, char)) in disk_map.char_indices().enumerate() {
+            let count = char
+                .to_digit(10)
+                .ok_or_else(|| source.char_error(disk_map, char_idx, "expected a digit"))?;
+
+            if idx % 2 == 0 {
+                // the compaction looks for every file by its id
+                if count == 0 {
+                    return Err(source.char_error(disk_map, char_idx, "a file needs a block"));
+                }
+
+                blocks.extend(iter::repeat_n(Block::File(file_id), count as usize));
+
+                file_id += 1;
+            } else {
+                blocks.extend(iter::repeat_n(Block::Empty, count as usize));
+            }
+        }
+
+        if file_id == 0 {
+            return Err(source.error(disk_map, "disk map is empty"));
+        }
+
+        Ok(Input {
+            blocks,
+            last_file_id: file_id - 1,
+        })
+    }
+}
+
+type Output = u64;
+
+type BlockSize = u8;
+
+trait GetBlockSize {
+    fn block_size_forwards(&self, block_idx: usize) -> BlockSize;
+    fn block_size_backwards(&self, block_dx: usize) -> BlockSize;
+}
+
+impl GetBlockSize for Blocks {
+    fn block_size_forwards(&self, block_idx: usize) -> BlockSize {
+        let block = self[block_idx];
+
+        let mut end_idx = block_idx;
+
+        while let Some(peeked_block) = self.get(end_idx) {
+            if *peeked_block == block && end_idx != self.len() {
+                end_idx += 1;
+            } else {
+                break;
+            }
+        }
+
+        (end_idx - block_idx) as BlockSize
+    }
+
+    fn block_size_backwards(&self, block_idx: usize) -> BlockSize {
+        let block = self[block_idx];
+
+        let mut start_idx = block_idx;
+
+        while let Some(peeked_block) = self.get(start_idx) {
+            if *peeked_block == block {
+                if start_idx == 0 {
+                    // this prevents underflow
+                    return (block_idx - start_idx + 1) as BlockSize;
+                }
+
+                start_idx -= 1;
+            } else {
+                break;
+            }
+        }
+
+        (block_idx - start_idx) as BlockSize
+    }
+}
+
+#[test]
+fn test_parse_empty_files() {
+    let zero = Parser.parse(&Source::new("zero", "0\n"));
+
+    assert!(
+        zero.err().map(|error| error.to_string())
+            == Some(String::from("zero:1:1: a file needs a block (found \"0\")"))
+    );
+    assert!(Parser.parse(&Source::new("empty", "\n")).is_err());
+    assert!(Parser.parse(&Source::new("last", "120\n")).is_err());
+    assert!(Parser.parse(&Source::new("gap", "101\n")).is_ok());
+}
+
+#[test]
+fn test_block_size_forwards() {
+    let blocks = vec![
+        Block::Empty,
+        Block::Empty,
+        Block::Empty,
+        Block::File(0),
+        Block::File(0),
+        Block::Empty,
+    ];
+
+    assert!(blocks.block_size_forwards(0) == 3);
+    assert!(blocks.block_size_forwards(1) == 2);
+    assert!(blocks.block_size_forwards(2) == 1);
+    assert!(blocks.block_size_forwards(3) == 2);
+    assert!(blocks.block_size_forwards(4) == 1);
+    assert!(blocks.block_size_forwards(5) == 1);
+}
+
+#[test]
+fn test_block_size_backwards() {
+    let blocks = vec![
+        Block::Empty,
+        Block::Empty,
+        Block::Empty,
+        Block::File(0),
+        Block::File(0),
+        Block::Empty,
+    ];
+
+    assert!(blocks.block_size_backwards(5) == 1);
+    assert!(blocks.block_size_backwards(4) == 2);
+    assert!(blocks.block_size_backwards(3) == 1);
+    assert!(blocks.block_size_backwards(2) == 3);
+    assert!(blocks.block_size_backwards(1) == 2);
+    assert!(blocks.block_size_backwards(0) == 1);
+}
+
+fn checksum(blocks: &Blocks) -> Output {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(idx, block)| match block {
+            Block::File(file_id) => idx as u64 * *file_id as u64,
+            _ => 0,
+        })
+        .sum()
+}
+
+// moves single blocks from the end into the gaps at the start, passing the swapped indices to `swapped`
+fn compact_blocks(blocks: &mut Blocks, context: &Context, mut swapped: impl FnMut(usize, usize)) {
+    let mut forwards_idx = 0;
+    let mut backwards_idx = blocks.len() - 1;
+
+    while forwards_idx < backwards_idx {
+        if blocks[forwards_idx] != Block::Empty {
+            forwards_idx += 1;
+        } else if blocks[backwards_idx] == Block::Empty {
+            backwards_idx -= 1;
+        } else {
+            context.trace(|| format!("moved block {} to {}", backwards_idx, forwards_idx));
+
+            blocks.swap(forwards_idx, backwards_idx);
+            swapped(forwards_idx, backwards_idx);
+        }
+    }
+}
+
+// moves whole files from the end into the first gap they fit in, block by block
+fn compact_files(
+    blocks: &mut Blocks,
+    last_file_id: FileId,
+    context: &Context,
+    mut swapped: impl FnMut(usize, usize),
+) {
+    let mut curr_file_id = last_file_id;
+    let mut curr_file_end_idx = blocks.len() - 1;
+
+    loop {
+        while blocks[curr_file_end_idx] != Block::File(curr_file_id) {
+            curr_file_end_idx -= 1;
+        }
+
+        let curr_file_size = blocks.block_size_backwards(curr_file_end_idx);
+
+        let mut forwards_idx = 0;
+
+        while let Some(block) = blocks.get(forwards_idx) {
+            if forwards_idx >= curr_file_end_idx {
+                break;
+            }
+
+            let block_size = blocks.block_size_forwards(forwards_idx);
+
+            if *block == Block::Empty && block_size >= curr_file_size {
+                context.debug(|| {
+                    format!(
+                        "moved file {} of size {} to {}",
+                        curr_file_id, curr_file_size, forwards_idx
+                    )
+                });
+
+                for _ in 0..curr_file_size {
+                    blocks.swap(forwards_idx, curr_file_end_idx);
+                    swapped(forwards_idx, curr_file_end_idx);
+
+                    forwards_idx += 1;
+                    curr_file_end_idx -= 1;
+                }
+
+                break;
+            }
+
+            forwards_idx += block_size as usize;
+        }
+
+        if curr_file_id == 0 {
+            break;
+        }
+
+        curr_file_id -= 1;
+    }
+}
+
+// a real disk has tens of thousands of blocks, so they are wrapped into rows
+const DISK_ROW_WIDTH: usize = 100;
+
+fn block_position(idx: usize, width: usize) -> Position {
+    Position::new(idx % width, idx / width)
+}
+
+fn block_cell(block: Block) -> Cell {
+    match block {
+        Block::Empty => Cell {
+            char: '.',
+            color: None,
+            tint: None,
+        },
+        Block::File(file_id) => Cell {
+            // the ids wrap around, the colors tell neighboring files apart
+            char: char::from_digit(file_id % 36, 36).unwrap(),
+            color: Some(Color::nth(file_id as usize)),
+            tint: None,
+        },
+    }
+}
+
+// the cells after the last block are left blank
+fn disk_canvas(blocks: &Blocks) -> Canvas {
+    let width = blocks.len().min(DISK_ROW_WIDTH);
+    let mut canvas = Canvas::new(
+        &Grid::new(width, blocks.len().div_ceil(width), ' '),
+        |char| *char,
+    );
+
+    for (idx, block) in blocks.iter().enumerate() {
+        canvas.set(block_position(idx, width), block_cell(*block));
+    }
+
+    canvas
+}
+
+struct Solver;
+
+impl ProblemSolver<Input, Output> for Solver {
+    fn solve_part_one(&self, input: &Input, context: &Context) -> Output {
+        let mut blocks = input.blocks.clone();
+
+        compact_blocks(&mut blocks, context, |_, _| {});
+
+        checksum(&blocks)
+    }
+
+    fn solve_part_two(&self, input: &Input, context: &Context) -> Output {
+        let mut blocks = input.blocks.clone();
+
+        compact_files(&mut blocks, input.last_file_id, context, |_, _| {});
+
+        checksum(&blocks)
+    }
+
+    // the disk after compacting it
+    fn render(&self, input: &Input, context: &Context) -> Option<Canvas> {
+        self.animate(input, context, &mut |_| {})
+    }
+
+    // a frame for every swap of two blocks
+    fn animate(
+        &self,
+        input: &Input,
+        context: &Context,
+        frame: &mut dyn FnMut(&Canvas),
+    ) -> Option<Canvas> {
+        let mut blocks = input.blocks.clone();
+        let mut canvas = disk_canvas(&blocks);
+        let width = canvas.cells().width();
+
+        frame(&canvas);
+
+        let mut swapped = |a: usize, b: usize| {
+            let (a, b) = (block_position(a, width), block_position(b, width));
+            let (cell_a, cell_b) = (canvas.cells()[a], canvas.cells()[b]);
+
+            canvas.set(a, cell_b);
+            canvas.set(b, cell_a);
+            frame(&canvas);
+        };
+
+        match context.part() {
+            Part::One => compact_blocks(&mut blocks, context, &mut swapped),
+            Part::Two => compact_files(&mut blocks, input.last_file_id, context, &mut swapped),
+        }
+
+        Some(canvas)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(9, Parser, Solver));
+}
+
+aoc_2024::answer_tests!("09", Parser, Solver);
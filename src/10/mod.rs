@@ -0,0 +1,194 @@
+use std::{
+    collections::BTreeSet,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use aoc_2024::{
+    Canvas, Color, Context, Grid, InputAssumption, InputParser, ParamSpec, ParseError, Position,
+    ProblemSolver, Registry, Runner, Source,
+};
+
+type Height = u8;
+
+const MIN_HEIGHT: Height = 0;
+const MAX_HEIGHT: Height = 9;
+
+type Map = Grid<Height>;
+
+type HikingScore = usize;
+
+struct HikeTrailScorer<'a> {
+    map: &'a Map,
+    context: &'a Context<'a>,
+    max_height: Height,
+    trail_hashes: BTreeSet<u64>,
+    summits: BTreeSet<Position>,
+    trail_positions: BTreeSet<Position>,
+}
+
+impl<'a> HikeTrailScorer<'a> {
+    fn new(map: &'a Map, context: &'a Context<'a>) -> Self {
+        let max_height = context
+            .params()
+            .unsigned_as("max_height")
+            .unwrap_or(MAX_HEIGHT);
+
+        Self {
+            map,
+            context,
+            max_height,
+            trail_hashes: BTreeSet::new(),
+            summits: BTreeSet::new(),
+            trail_positions: BTreeSet::new(),
+        }
+    }
+
+    // number of distinct summits reachable from the trailhead
+    fn score(mut self, position: Position) -> HikingScore {
+        self.traverse(DefaultHasher::new(), position);
+        self.summits.len()
+    }
+
+    // number of distinct trails leading from the trailhead to any summit
+    fn rate(mut self, position: Position) -> HikingScore {
+        self.traverse(DefaultHasher::new(), position);
+        self.trail_hashes.len()
+    }
+
+    // every position on any of the trails leading from the trailhead to a summit
+    fn trails(mut self, position: Position) -> BTreeSet<Position> {
+        self.traverse(DefaultHasher::new(), position);
+        self.trail_positions
+    }
+
+    // returns whether a summit can be reached from `position`
+    fn traverse(&mut self, mut hasher: DefaultHasher, position: Position) -> bool {
+        position.hash(&mut hasher);
+
+        let height = self.map[position];
+
+        if height == self.max_height {
+            self.context
+                .trace(|| format!("reached the summit at {},{}", position.x, position.y));
+
+            self.trail_hashes.insert(hasher.finish());
+            self.summits.insert(position);
+            self.trail_positions.insert(position);
+            return true;
+        }
+
+        let map = self.map;
+        let mut on_trail = false;
+
+        for next_position in map
+            .neighbors4(position)
+            .filter(|position| map[*position] == height + 1)
+        {
+            on_trail |= self.traverse(hasher.clone(), next_position);
+        }
+
+        if on_trail {
+            self.trail_positions.insert(position);
+        }
+
+        on_trail
+    }
+}
+
+type Input = Map;
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        Grid::parse(source, |_, height| {
+            if height == '.' {
+                return Ok(MAX_HEIGHT + 2); // impassable height for debugging
+            }
+
+            height
+                .to_digit(10)
+                .map(|height| height as Height)
+                .ok_or("expected a height")
+        })
+    }
+
+    fn assumptions(&self) -> Vec<InputAssumption> {
+        vec![
+            InputAssumption::Rectangular,
+            InputAssumption::Chars("0123456789."),
+        ]
+    }
+}
+
+struct Solver;
+
+type Output = HikingScore;
+
+fn trailheads(map: &Map) -> impl Iterator<Item = Position> + '_ {
+    map.positions()
+        .filter(|position| map[*position] == MIN_HEIGHT)
+}
+
+impl ProblemSolver<Input, Output> for Solver {
+    fn solve_part_one(&self, map: &Input, context: &Context) -> Output {
+        trailheads(map)
+            .map(|hike_start_pos| {
+                let (x, y) = (hike_start_pos.x, hike_start_pos.y);
+                let score = HikeTrailScorer::new(map, context).score(hike_start_pos);
+
+                context.debug(|| format!("trailhead at {},{} has a score of {}", x, y, score));
+
+                score
+            })
+            .sum()
+    }
+
+    fn solve_part_two(&self, map: &Input, context: &Context) -> Output {
+        trailheads(map)
+            .map(|hike_start_pos| {
+                let (x, y) = (hike_start_pos.x, hike_start_pos.y);
+                let rating = HikeTrailScorer::new(map, context).rate(hike_start_pos);
+
+                context.debug(|| format!("trailhead at {},{} has a rating of {}", x, y, rating));
+
+                rating
+            })
+            .sum()
+    }
+
+    // the same for both parts, only the heights on a trail from a trailhead to a summit are shown
+    fn render(&self, map: &Input, context: &Context) -> Option<Canvas> {
+        let mut canvas = Canvas::new(map, |_| '.');
+
+        for trailhead in trailheads(map) {
+            let scorer = HikeTrailScorer::new(map, context);
+            let max_height = scorer.max_height;
+
+            for position in scorer.trails(trailhead) {
+                let height = map[position];
+                let color = match height {
+                    MIN_HEIGHT => Color::Green,
+                    _ if height == max_height => Color::Red,
+                    _ => Color::Cyan,
+                };
+
+                canvas.mark(position, (b'0' + height) as char, color);
+            }
+        }
+
+        Some(canvas)
+    }
+
+    fn params(&self) -> Vec<ParamSpec> {
+        vec![ParamSpec::unsigned("max_height", Some(MAX_HEIGHT as u64))
+            .fitting::<Height>()
+            .at_most(MAX_HEIGHT as u64)]
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(10, Parser, Solver));
+}
+
+aoc_2024::answer_tests!("10", Parser, Solver);
@@ -1,6 +1,8 @@
-use std::{collections::HashMap, fs::read_to_string};
+use std::collections::HashMap;
 
-use aoc_2024::{InputParser, ProblemSolver, Runner};
+use aoc_2024::{
+    Context, InputParser, ParamSpec, ParseError, ProblemSolver, Registry, Runner, Source,
+};
 
 type StoneNumber = u64;
 
@@ -17,7 +19,7 @@ impl Stone {
 
         let digits = self.number.ilog10() + 1;
 
-        if digits % 2 == 0 {
+        if digits.is_multiple_of(2) {
             let half_digits = digits / 2;
             let first_denominator = (10 as StoneNumber).pow(half_digits);
 
@@ -55,12 +57,14 @@ type Input = Vec<Stone>;
 struct Parser;
 
 impl InputParser<Input> for Parser {
-    fn parse(&self, filename: &str) -> Input {
-        let file = read_to_string(filename).unwrap();
-
-        file.split_ascii_whitespace()
-            .map(|number| Stone {
-                number: number.parse().unwrap(),
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        source
+            .text()
+            .split_ascii_whitespace()
+            .map(|number| {
+                Ok(Stone {
+                    number: source.parse(number)?,
+                })
             })
             .collect()
     }
@@ -70,9 +74,12 @@ type Output = usize;
 
 struct Solver;
 
-type BlinkCount = u8;
+type BlinkCount = u32;
 
-const BLINK_COUNT: BlinkCount = 75;
+const PART_ONE_BLINK_COUNT: BlinkCount = 25;
+const PART_TWO_BLINK_COUNT: BlinkCount = 75;
+// the stones of an input outnumber a u64 soon after 100 blinks, and every blink is a recursive call
+const MAX_BLINK_COUNT: BlinkCount = 90;
 
 #[derive(Default)]
 struct StoneBlinker {
@@ -105,17 +112,45 @@ impl StoneBlinker {
     }
 }
 
+fn blinks(context: &Context, default: BlinkCount) -> BlinkCount {
+    context.params().unsigned_as("blinks").unwrap_or(default)
+}
+
+fn count_stones(stones: &Input, blink_count: BlinkCount) -> Output {
+    let mut stone_blinker = StoneBlinker::default();
+
+    stones
+        .iter()
+        .map(|stone| stone_blinker.blink_at_stone(*stone, blink_count))
+        .sum()
+}
+
+#[test]
+fn test_count_stones() {
+    let stones = Parser.parse(&Source::new("example", "125 17")).unwrap();
+
+    assert!(count_stones(&stones, 6) == 22);
+}
+
 impl ProblemSolver<Input, Output> for Solver {
-    fn solve(&self, stones: Input) -> Output {
-        let mut stone_blinker = StoneBlinker::default();
+    fn solve_part_one(&self, stones: &Input, context: &Context) -> Output {
+        count_stones(stones, blinks(context, PART_ONE_BLINK_COUNT))
+    }
 
-        stones
-            .into_iter()
-            .map(|stone| stone_blinker.blink_at_stone(stone, BLINK_COUNT))
-            .sum()
+    fn solve_part_two(&self, stones: &Input, context: &Context) -> Output {
+        count_stones(stones, blinks(context, PART_TWO_BLINK_COUNT))
+    }
+
+    fn params(&self) -> Vec<ParamSpec> {
+        // each part has its own default
+        vec![ParamSpec::unsigned("blinks", None)
+            .fitting::<BlinkCount>()
+            .at_most(MAX_BLINK_COUNT as u64)]
     }
 }
 
-fn main() {
-    Runner::new(Parser, Solver).run(&vec!["src/11/input_1.txt", "src/11/input_2.txt"]);
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(11, Parser, Solver));
 }
+
+aoc_2024::answer_tests!("11", Parser, Solver);
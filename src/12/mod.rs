@@ -0,0 +1,266 @@
+use std::{collections::HashSet, hash::Hash};
+
+use aoc_2024::{
+    Canvas, Color, Context, Direction, Grid, InputAssumption, InputParser, ParseError, Position,
+    ProblemSolver, Registry, Runner, Source,
+};
+
+type PlantType = u8;
+
+type Map = Grid<PlantType>;
+
+type Input = Map;
+
+struct Parser;
+
+impl InputParser<Input> for Parser {
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {
+        Grid::parse(source, |_, plant_type| {
+            if !plant_type.is_ascii() {
+                return Err("expected an ASCII plant type");
+            }
+
+            Ok(plant_type as PlantType)
+        })
+    }
+
+    fn assumptions(&self) -> Vec<InputAssumption> {
+        vec![InputAssumption::Rectangular, InputAssumption::Ascii]
+    }
+}
+
+type Price = u64;
+
+type Area = u32;
+
+// the side points out of the region, possibly off the map
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Border {
+    in_region: Position,
+    side: Direction,
+}
+
+impl Border {
+    fn border_dir(&self) -> Direction {
+        match self.side {
+            Direction::Up | Direction::Down => Direction::Right,
+            _ => Direction::Down,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Pricing {
+    Perimeter,
+    Sides,
+}
+
+struct RegionFinder<'a> {
+    visited: HashSet<Position>,
+    borders: HashSet<Border>,
+    // the positions of the region found last
+    region: Vec<Position>,
+    map: &'a Map,
+}
+
+impl<'a> RegionFinder<'a> {
+    fn new(map: &'a Map) -> Self {
+        RegionFinder {
+            map,
+            borders: HashSet::new(),
+            visited: HashSet::new(),
+            region: Vec::new(),
+        }
+    }
+
+    // none if the region at the position was found before
+    fn find_new_region(&mut self, position: Position) -> Option<Vec<Position>> {
+        if self.visited.contains(&position) {
+            return None;
+        }
+
+        self.find_region(self.map[position], position);
+        self.borders.clear();
+
+        Some(std::mem::take(&mut self.region))
+    }
+
+    fn find_new_region_price(&mut self, position: Position, pricing: Pricing) -> Price {
+        if self.visited.contains(&position) {
+            return 0;
+        }
+
+        let plant_type = self.map[position];
+
+        let area = self.find_region(plant_type, position);
+        self.region.clear();
+
+        let fence_count = match pricing {
+            Pricing::Perimeter => {
+                let perimeter = self.borders.len() as u32;
+                self.borders.clear();
+                perimeter
+            }
+            Pricing::Sides => self.count_sides(plant_type),
+        };
+
+        area as Price * fence_count as Price
+    }
+
+    fn find_region(&mut self, plant_type: PlantType, position: Position) -> Area {
+        if self.visited.contains(&position) {
+            return 0;
+        }
+
+        self.visited.insert(position);
+        self.region.push(position);
+
+        let mut area = 1;
+
+        for side in Direction::CARDINAL {
+            match self.neighbor_in_region(plant_type, position, side) {
+                Some(neighboring_position) => {
+                    area += self.find_region(plant_type, neighboring_position)
+                }
+                None => {
+                    self.borders.insert(Border {
+                        in_region: position,
+                        side,
+                    });
+                }
+            }
+        }
+
+        area
+    }
+
+    fn neighbor_in_region(
+        &self,
+        plant_type: PlantType,
+        position: Position,
+        dir: Direction,
+    ) -> Option<Position> {
+        self.map
+            .step(position, dir)
+            .filter(|neighboring_position| self.map[*neighboring_position] == plant_type)
+    }
+
+    fn remove_borders_in_dir(&mut self, plant_type: PlantType, mut border: Border, dir: Direction) {
+        loop {
+            self.borders.remove(&border);
+
+            // the side continues while the next cell is in the region but its neighbor is not
+            match self.neighbor_in_region(plant_type, border.in_region, dir) {
+                Some(next_position)
+                    if self
+                        .neighbor_in_region(plant_type, next_position, border.side)
+                        .is_none() =>
+                {
+                    border.in_region = next_position
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn count_sides(&mut self, plant_type: PlantType) -> u32 {
+        let mut sides = 0;
+
+        while let Some(border) = self.borders.iter().next().cloned() {
+            sides += 1;
+
+            let dir = border.border_dir();
+
+            self.remove_borders_in_dir(plant_type, border.clone(), dir);
+            self.remove_borders_in_dir(plant_type, border, dir.reverse());
+        }
+
+        sides
+    }
+}
+
+type Output = Price;
+
+struct Solver;
+
+fn total_price(input: &Input, pricing: Pricing) -> Output {
+    let mut region_finder = RegionFinder::new(input);
+
+    input
+        .positions()
+        .map(|position| region_finder.find_new_region_price(position, pricing))
+        .sum()
+}
+
+impl ProblemSolver<Input, Output> for Solver {
+    fn solve_part_one(&self, input: &Input, _context: &Context) -> Output {
+        total_price(input, Pricing::Perimeter)
+    }
+
+    fn solve_part_two(&self, input: &Input, _context: &Context) -> Output {
+        total_price(input, Pricing::Sides)
+    }
+
+    // the same for both parts, every region tinted differently from the regions next to it
+    fn render(&self, input: &Input, _context: &Context) -> Option<Canvas> {
+        let mut canvas = Canvas::new(input, |plant_type| *plant_type as char);
+        let mut tints = input.map(|_| None);
+        let mut region_finder = RegionFinder::new(input);
+
+        for position in input.positions() {
+            let Some(region) = region_finder.find_new_region(position) else {
+                continue;
+            };
+
+            let neighboring_tints: Vec<Color> = region
+                .iter()
+                .flat_map(|position| {
+                    Direction::CARDINAL
+                        .into_iter()
+                        .filter_map(|dir| input.step(*position, dir))
+                })
+                .filter_map(|neighboring_position| tints[neighboring_position])
+                .collect();
+
+            // a color no neighbor has, or else the one touching the region the least
+            let tint = Color::PALETTE
+                .into_iter()
+                .min_by_key(|color| {
+                    neighboring_tints
+                        .iter()
+                        .filter(|tint| *tint == color)
+                        .count()
+                })
+                .unwrap();
+
+            for position in region {
+                tints[position] = Some(tint);
+                canvas.tint(position, tint);
+            }
+        }
+
+        Some(canvas)
+    }
+}
+
+pub fn register(registry: &mut Registry) {
+    registry.register(Runner::new(12, Parser, Solver));
+}
+
+#[test]
+fn test_render_regions() {
+    // `A` and `G` would get the same color if the tint was chosen by plant type
+    let source = Source::new("example", "AAG\nGGG\nAAG\n");
+    let map = Parser.parse(&source).unwrap();
+    let canvas = Solver
+        .render(&map, &Context::disabled(aoc_2024::Part::One))
+        .unwrap();
+    let tint = |x, y| canvas.cells()[Position::new(x, y)].tint.unwrap();
+
+    assert!(tint(0, 0) == tint(1, 0));
+    assert!(tint(0, 0) != tint(2, 0));
+    assert!(tint(2, 0) == tint(0, 1));
+    assert!(tint(0, 2) != tint(0, 1));
+}
+
+aoc_2024::answer_tests!("12", Parser, Solver);
@@ -0,0 +1,176 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Allocations made while running a single step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    // most bytes that were allocated at the same time, above what was live when the step started
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    live: u64,
+    peak_live: u64,
+}
+
+thread_local! {
+    // const initialized without a destructor, so the allocator can use it at any time
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak_live: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Counters)) {
+    // fails while the thread is being torn down, those allocations are not interesting
+    let _ = COUNTERS.try_with(|counters| {
+        let mut value = counters.get();
+        f(&mut value);
+        counters.set(value);
+    });
+}
+
+fn allocated(size: usize) {
+    update(|counters| {
+        counters.allocations += 1;
+        counters.bytes += size as u64;
+        counters.live += size as u64;
+        counters.peak_live = counters.peak_live.max(counters.live);
+    });
+}
+
+fn freed(size: usize) {
+    update(|counters| counters.live = counters.live.saturating_sub(size as u64));
+}
+
+/// The system allocator, counting the allocations of every thread separately.
+/// It is the global allocator when the `alloc-stats` feature is enabled.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Counts the allocations of the current thread while running `step`.
+/// Returns no stats unless the `alloc-stats` feature is enabled.
+pub fn measure_allocations<T>(step: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (step(), None);
+    }
+
+    let before = COUNTERS.with(|counters| {
+        let mut value = counters.get();
+        value.peak_live = value.live;
+        counters.set(value);
+
+        value
+    });
+
+    let result = step();
+
+    let after = COUNTERS.with(Cell::get);
+
+    let stats = AllocStats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: after.peak_live - before.live,
+    };
+
+    (result, Some(stats))
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.2} {}", value, UNITS[unit])
+    }
+}
+
+#[test]
+fn test_format_bytes() {
+    assert!(format_bytes(512) == "512 B");
+    assert!(format_bytes(1536) == "1.50 KiB");
+    assert!(format_bytes(3 * 1024 * 1024) == "3.00 MiB");
+}
+
+#[cfg(feature = "alloc-stats")]
+#[test]
+fn test_measure_allocations() {
+    let (_, stats) = measure_allocations(|| {
+        let first = vec![0u8; 1000];
+        drop(first);
+
+        vec![0u8; 600]
+    });
+
+    let stats = stats.unwrap();
+
+    assert!(stats.allocations == 2);
+    assert!(stats.bytes == 1600);
+    assert!(stats.peak == 1000);
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+#[test]
+fn test_allocations_are_not_measured() {
+    assert!(measure_allocations(|| vec![0u8; 10]).1.is_none());
+}
@@ -0,0 +1,36 @@
+// included by build.rs as well to generate a test per entry, so this may only use std
+
+/// A line of an answers file, `<input file name> <part> <answer>` separated by any whitespace.
+pub struct AnswerEntry<'a> {
+    pub file_name: &'a str,
+    pub part: &'a str,
+    pub answer: &'a str,
+}
+
+impl<'a> AnswerEntry<'a> {
+    /// None for empty lines and lines starting with `#`.
+    /// An error holds the part of the line that is wrong along with the message.
+    pub fn parse(line: &'a str) -> Result<Option<Self>, (&'a str, &'static str)> {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let (file_name, rest) = line
+            .split_once(char::is_whitespace)
+            .ok_or((line, "expected a part and an answer after the file name"))?;
+
+        let rest = rest.trim_start();
+
+        let (part, answer) = rest
+            .split_once(char::is_whitespace)
+            .ok_or((rest, "expected an answer after the part"))?;
+
+        Ok(Some(AnswerEntry {
+            file_name,
+            part,
+            answer: answer.trim_start(),
+        }))
+    }
+}
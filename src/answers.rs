@@ -0,0 +1,150 @@
+use std::{collections::HashMap, fmt::Display, path::Path};
+
+use crate::{
+    answer_entry::AnswerEntry,
+    options::Part,
+    parse::{Source, STDIN_INPUT},
+    ParseError,
+};
+
+/// Name of the file next to the `input_N.txt` files holding their known answers.
+pub const ANSWERS_FILE_NAME: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+    // the solver ran out of its time budget, its answer is meaningless
+    Timeout,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Unknown => write!(f, "unknown"),
+            Status::Timeout => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+impl Status {
+    /// Lowercase name used by the machine readable output formats.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unknown => "unknown",
+            Status::Timeout => "timeout",
+        }
+    }
+}
+
+/// Expected answers of a day, one `<input file name> <part> <answer>` entry per line.
+/// Empty lines and lines starting with `#` are ignored, see `AnswerEntry`.
+#[derive(Default)]
+pub struct Answers {
+    expected: HashMap<(String, Part), String>,
+}
+
+impl Answers {
+    pub fn parse(source: &Source) -> Result<Self, ParseError> {
+        let mut expected = HashMap::new();
+
+        for line in source.lines() {
+            let entry = match AnswerEntry::parse(line) {
+                Ok(Some(entry)) => entry,
+                Ok(None) => continue,
+                Err((at, message)) => return Err(source.error(at, message)),
+            };
+
+            let part = source.parse::<Part>(entry.part)?;
+
+            // each entry is also a test, named after the file and the part
+            if expected
+                .insert(
+                    (entry.file_name.to_string(), part),
+                    entry.answer.to_string(),
+                )
+                .is_some()
+            {
+                return Err(source.error(
+                    line.trim(),
+                    format!("duplicate answer for {} part {}", entry.file_name, part),
+                ));
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+
+    /// Loads the answers stored next to `input_file`, a missing answers file means no answers are known.
+    pub fn for_input(input_file: &str) -> Result<Self, ParseError> {
+        let answers_file = Path::new(input_file).with_file_name(ANSWERS_FILE_NAME);
+
+        if input_file == STDIN_INPUT || !answers_file.exists() {
+            return Ok(Answers::default());
+        }
+
+        Answers::parse(&Source::read(&answers_file.to_string_lossy())?)
+    }
+
+    pub fn expected(&self, input_file: &str, part: Part) -> Option<&str> {
+        let file_name = Path::new(input_file).file_name()?.to_string_lossy();
+
+        self.expected
+            .get(&(file_name.to_string(), part))
+            .map(String::as_str)
+    }
+
+    pub fn check(&self, input_file: &str, part: Part, answer: &str) -> Status {
+        match self.expected(input_file, part) {
+            None => Status::Unknown,
+            Some(expected) if expected == answer => Status::Pass,
+            Some(_) => Status::Fail,
+        }
+    }
+}
+
+#[test]
+fn test_check_answers() {
+    let source = Source::new(
+        "answers.txt",
+        "# file part answer\ninput_1.txt 1 11\ninput_1.txt 2 31\n",
+    );
+
+    let answers = Answers::parse(&source).unwrap();
+
+    assert!(answers.check("src/01/input_1.txt", Part::One, "11") == Status::Pass);
+    assert!(answers.check("src/01/input_1.txt", Part::Two, "30") == Status::Fail);
+    assert!(answers.check("src/01/input_2.txt", Part::One, "11") == Status::Unknown);
+}
+
+#[test]
+fn test_invalid_answers() {
+    let source = Source::new("answers.txt", "input_1.txt 3 11\n");
+
+    let error = Answers::parse(&source).err().unwrap();
+
+    assert!(error.location.unwrap().text == "3");
+}
+
+#[test]
+fn test_answers_whitespace() {
+    let source = Source::new("answers.txt", "input_1.txt\t1  11\n");
+
+    let answers = Answers::parse(&source).unwrap();
+
+    assert!(answers.check("src/01/input_1.txt", Part::One, "11") == Status::Pass);
+}
+
+#[test]
+fn test_duplicate_answers() {
+    let source = Source::new("answers.txt", "input_1.txt 1 11\ninput_1.txt 1 12\n");
+
+    let error = Answers::parse(&source).err().unwrap();
+
+    assert!(error.to_string() == "answers.txt:2:1: duplicate answer for input_1.txt part 1 (found \"input_1.txt 1 12\")");
+}
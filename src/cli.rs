@@ -0,0 +1,935 @@
+use std::{path::Path, process::ExitCode, str::FromStr};
+
+use crate::{
+    ansi_supported, format_duration,
+    history::{append_history, compare, git_revision, load_history, HistoryEntry, HISTORY_FILE},
+    inspect::InputStats,
+    options::RUN_OPTIONS_USAGE,
+    output::new_reporter,
+    registry::{Day, DayNumber, Registry},
+    run_ordered,
+    scaffold::create_day,
+    site::{cached_input, install_input, Site, CACHE_DIR},
+    submit::{now, parse_response, Attempt, Ledger, Outcome, LEDGER_FILE},
+    watch::watch,
+    FrameWriter, ImageFormat, OutputFormat, ParamArg, Part, PartSelection, RunOptions, RunReport,
+    Source, StderrObserver, TraceFilter, Tracer,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Day(DayNumber),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "all" {
+            return Ok(DaySelection::All);
+        }
+
+        match value.parse() {
+            Ok(day) if (1..=25).contains(&day) => Ok(DaySelection::Day(day)),
+            _ => Err(format!("invalid day {:?}, expected 1 to 25 or all", value)),
+        }
+    }
+}
+
+/// The input and part drawn by `aoc show` and `aoc export`.
+#[derive(Debug)]
+pub struct PictureOptions {
+    pub part: Part,
+    // the first default input of the day by default
+    pub input: Option<String>,
+    pub params: Vec<ParamArg>,
+}
+
+impl Default for PictureOptions {
+    fn default() -> Self {
+        PictureOptions {
+            part: Part::One,
+            input: None,
+            params: Vec::new(),
+        }
+    }
+}
+
+impl PictureOptions {
+    /// Returns false if `arg` is not a picture option, like `RunOptions::parse_arg`.
+    fn parse_arg(
+        &mut self,
+        arg: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--part" => self.part = args.next().ok_or("missing value for --part")?.parse()?,
+            "--input" => self.input = Some(args.next().ok_or("missing value for --input")?),
+            "--param" => self
+                .params
+                .push(args.next().ok_or("missing value for --param")?.parse()?),
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Help,
+    Run {
+        days: DaySelection,
+        // overrides the default inputs of the day
+        inputs: Vec<String>,
+        options: RunOptions,
+        // appends the timings to the benchmark history
+        record: bool,
+        trace: Vec<TraceFilter>,
+    },
+    Compare {
+        days: DaySelection,
+        // compares with the latest entries of this revision instead of the previous revision
+        base: Option<String>,
+        // relative slowdown of the median solve time that counts as a regression
+        threshold: f64,
+    },
+    New {
+        day: DayNumber,
+    },
+    Fetch {
+        day: DayNumber,
+        // overrides the base URL from the environment
+        base_url: Option<String>,
+    },
+    Submit {
+        day: DayNumber,
+        part: Part,
+        // the personal input fetched by `aoc fetch` by default
+        input: Option<String>,
+        base_url: Option<String>,
+    },
+    Watch {
+        day: DayNumber,
+        // run options passed on to every run
+        args: Vec<String>,
+    },
+    Inspect {
+        day: DayNumber,
+        input: String,
+    },
+    Show {
+        day: DayNumber,
+        picture: PictureOptions,
+        // leaves out the colors even on a terminal
+        plain: bool,
+    },
+    Export {
+        day: DayNumber,
+        // the image, or the directory of the frames
+        path: String,
+        picture: PictureOptions,
+        // taken from the extension of the image by default, frames are PPM by default
+        format: Option<ImageFormat>,
+        // pixels per cell
+        scale: usize,
+        // animates the solution instead of drawing its end
+        frames: bool,
+        // only keeps every Nth frame, and the last one
+        every: usize,
+    },
+}
+
+/// Large enough to tell the cells apart, and to read the characters of an SVG.
+const DEFAULT_SCALE: usize = 8;
+
+/// Median solve times more than 10% slower than the baseline are reported as regressions.
+const DEFAULT_REGRESSION_THRESHOLD: f64 = 0.1;
+
+fn usage() -> String {
+    format!(
+        "usage:\n  aoc run <day|all> [--input FILE|-]... [--record] [--trace [DAY=]info|debug|trace]... {}\n  aoc watch <day> {}\n  aoc compare <day|all> [--base REVISION] [--threshold PERCENT]\n  aoc new <day>\n  aoc fetch <day> [--base-url URL]\n  aoc submit <day> --part 1|2 [--input FILE] [--base-url URL]\n  aoc inspect <day> <FILE|->\n  aoc show <day> [--part 1|2] [--input FILE|-] [--param [DAY:]NAME=VALUE]... [--plain]\n  aoc export <day> <PATH> [--part 1|2] [--input FILE|-] [--param [DAY:]NAME=VALUE]... [--format ppm|svg] [--scale N] [--frames [--every N]]\n  aoc help",
+        RUN_OPTIONS_USAGE, RUN_OPTIONS_USAGE
+    )
+}
+
+fn parse_single_day(arg: Option<String>) -> Result<DayNumber, String> {
+    match arg.ok_or("missing day")?.parse()? {
+        DaySelection::Day(day) => Ok(day),
+        DaySelection::All => Err(String::from("expected a single day")),
+    }
+}
+
+impl Command {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+
+        match args.next().as_deref() {
+            None | Some("help") | Some("--help") | Some("-h") => Ok(Command::Help),
+            Some("run") => {
+                let days: DaySelection = args.next().ok_or("missing day")?.parse()?;
+
+                let mut inputs = Vec::new();
+                let mut options = RunOptions::default();
+                let mut record = false;
+                let mut trace = Vec::new();
+
+                while let Some(arg) = args.next() {
+                    if arg == "--input" {
+                        inputs.push(args.next().ok_or("missing value for --input")?);
+                    } else if arg == "--record" {
+                        record = true;
+                    } else if arg == "--trace" {
+                        trace.push(args.next().ok_or("missing value for --trace")?.parse()?);
+                    } else if !options.parse_arg(&arg, &mut args)? {
+                        return Err(format!("unknown argument {:?}", arg));
+                    }
+                }
+
+                if days == DaySelection::All && !inputs.is_empty() {
+                    return Err(String::from("--input can only be used with a single day"));
+                }
+
+                // the history only tells entries apart by day, part and input, and parallel
+                // solves slow each other down
+                if record && (!options.params.is_empty() || options.jobs > 1) {
+                    return Err(String::from(
+                        "--record cannot be used with --param or more than one job",
+                    ));
+                }
+
+                Ok(Command::Run {
+                    days,
+                    inputs,
+                    options,
+                    record,
+                    trace,
+                })
+            }
+            Some("compare") => {
+                let days: DaySelection = args.next().ok_or("missing day")?.parse()?;
+
+                let mut base = None;
+                let mut threshold = DEFAULT_REGRESSION_THRESHOLD;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--base" => base = Some(args.next().ok_or("missing value for --base")?),
+                        "--threshold" => {
+                            let value = args.next().ok_or("missing value for --threshold")?;
+
+                            threshold = match value.parse::<f64>() {
+                                Ok(percent) if percent >= 0.0 => percent / 100.0,
+                                _ => return Err(format!("invalid threshold {:?}", value)),
+                            };
+                        }
+                        _ => return Err(format!("unknown argument {:?}", arg)),
+                    }
+                }
+
+                Ok(Command::Compare {
+                    days,
+                    base,
+                    threshold,
+                })
+            }
+            Some("new") => {
+                let day = parse_single_day(args.next())?;
+
+                match args.next() {
+                    Some(arg) => Err(format!("unknown argument {:?}", arg)),
+                    None => Ok(Command::New { day }),
+                }
+            }
+            Some("fetch") => {
+                let day = parse_single_day(args.next())?;
+                let mut base_url = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--base-url" => {
+                            base_url = Some(args.next().ok_or("missing value for --base-url")?)
+                        }
+                        _ => return Err(format!("unknown argument {:?}", arg)),
+                    }
+                }
+
+                Ok(Command::Fetch { day, base_url })
+            }
+            Some("submit") => {
+                let day = parse_single_day(args.next())?;
+                let mut part = None;
+                let mut input = None;
+                let mut base_url = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--part" => {
+                            part = Some(args.next().ok_or("missing value for --part")?.parse()?)
+                        }
+                        "--input" => input = Some(args.next().ok_or("missing value for --input")?),
+                        "--base-url" => {
+                            base_url = Some(args.next().ok_or("missing value for --base-url")?)
+                        }
+                        _ => return Err(format!("unknown argument {:?}", arg)),
+                    }
+                }
+
+                Ok(Command::Submit {
+                    day,
+                    part: part.ok_or("missing --part")?,
+                    input,
+                    base_url,
+                })
+            }
+            Some("watch") => {
+                let day = parse_single_day(args.next())?;
+
+                let args: Vec<String> = args.collect();
+                let options = RunOptions::parse(args.clone())?;
+
+                if options.format != OutputFormat::Text {
+                    return Err(String::from("--format cannot be used with watch"));
+                }
+
+                Ok(Command::Watch { day, args })
+            }
+            Some("inspect") => {
+                let day = parse_single_day(args.next())?;
+                let input = args.next().ok_or("missing input file")?;
+
+                match args.next() {
+                    Some(arg) => Err(format!("unknown argument {:?}", arg)),
+                    None => Ok(Command::Inspect { day, input }),
+                }
+            }
+            Some("show") => {
+                let day = parse_single_day(args.next())?;
+                let mut picture = PictureOptions::default();
+                let mut plain = false;
+
+                while let Some(arg) = args.next() {
+                    if arg == "--plain" {
+                        plain = true;
+                    } else if !picture.parse_arg(&arg, &mut args)? {
+                        return Err(format!("unknown argument {:?}", arg));
+                    }
+                }
+
+                Ok(Command::Show {
+                    day,
+                    picture,
+                    plain,
+                })
+            }
+            Some("export") => {
+                let day = parse_single_day(args.next())?;
+                let path = args.next().ok_or("missing output path")?;
+                let mut picture = PictureOptions::default();
+                let mut format = None;
+                let mut scale = DEFAULT_SCALE;
+                let mut frames = false;
+                let mut every = None;
+
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--format" => {
+                            format = Some(args.next().ok_or("missing value for --format")?.parse()?)
+                        }
+                        "--scale" => {
+                            let value = args.next().ok_or("missing value for --scale")?;
+
+                            scale = match value.parse() {
+                                Ok(scale) if scale > 0 => scale,
+                                _ => return Err(format!("invalid scale {:?}", value)),
+                            };
+                        }
+                        "--frames" => frames = true,
+                        "--every" => {
+                            let value = args.next().ok_or("missing value for --every")?;
+
+                            every = match value.parse() {
+                                Ok(every) if every > 0 => Some(every),
+                                _ => return Err(format!("invalid frame interval {:?}", value)),
+                            };
+                        }
+                        _ if picture.parse_arg(&arg, &mut args)? => {}
+                        _ => return Err(format!("unknown argument {:?}", arg)),
+                    }
+                }
+
+                if every.is_some() && !frames {
+                    return Err(String::from("--every can only be used with --frames"));
+                }
+
+                Ok(Command::Export {
+                    day,
+                    path,
+                    picture,
+                    format,
+                    scale,
+                    frames,
+                    every: every.unwrap_or(1),
+                })
+            }
+            Some(command) => Err(format!("unknown command {:?}", command)),
+        }
+    }
+}
+
+fn select_days(registry: &Registry, days: DaySelection) -> Result<Vec<&dyn Day>, String> {
+    match days {
+        DaySelection::All => Ok(registry.days().collect()),
+        DaySelection::Day(day) => registry
+            .get(day)
+            .map(|entry| vec![entry])
+            .ok_or_else(|| format!("day {:02} is not registered", day)),
+    }
+}
+
+fn run(
+    registry: &Registry,
+    days: DaySelection,
+    inputs: &[String],
+    options: &RunOptions,
+    record: bool,
+    trace: &[TraceFilter],
+) -> ExitCode {
+    let selected = select_days(registry, days).and_then(|entries| {
+        let declared: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.number(), entry.params()))
+            .collect();
+
+        options.check_params(&declared)?;
+        Ok(entries)
+    });
+
+    let entries = match selected {
+        Ok(entries) => entries,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    // every input of every day is a separate task so that all of them can share the thread pool
+    let tasks: Vec<(&dyn Day, String)> = entries
+        .into_iter()
+        .flat_map(|entry| {
+            let inputs = if inputs.is_empty() {
+                entry.default_inputs()
+            } else {
+                inputs.to_vec()
+            };
+
+            inputs.into_iter().map(move |input| (entry, input))
+        })
+        .collect();
+
+    let mut report = RunReport::default();
+    let mut reporter = new_reporter(options.format, std::io::stdout().lock());
+    let mut write_result = Ok(());
+    let mut history = Vec::new();
+    let revision = if record {
+        git_revision()
+    } else {
+        String::new()
+    };
+
+    run_ordered(
+        options.jobs,
+        &tasks,
+        |(entry, input)| {
+            let mut options = options.for_day(entry.number(), &entry.params());
+
+            if let Some(level) = TraceFilter::level_for(trace, entry.number()) {
+                options.trace = Some(Tracer::new(level, StderrObserver));
+            }
+
+            entry.run(&options, &[input])
+        },
+        |idx, task_report| {
+            let day = tasks[idx].0.number();
+
+            if write_result.is_ok() {
+                write_result = reporter.report(day, &task_report);
+            }
+
+            if record {
+                history.extend(HistoryEntry::from_report(
+                    day,
+                    &task_report,
+                    &revision,
+                    options.repeat,
+                ));
+            }
+
+            report.extend(task_report);
+        },
+    );
+
+    if let Err(error) = write_result.and_then(|_| reporter.finish(&report)) {
+        eprintln!("Failed to write the results: {}", error);
+        return ExitCode::FAILURE;
+    }
+
+    if record {
+        if let Err(error) = append_history(HISTORY_FILE, &history) {
+            eprintln!("Failed to write {}: {}", HISTORY_FILE, error);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    report.exit_code()
+}
+
+fn compare_history(days: DaySelection, base: Option<&str>, threshold: f64) -> ExitCode {
+    let history = match load_history(HISTORY_FILE) {
+        Ok(history) => history,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let comparisons: Vec<_> = compare(&history, base)
+        .into_iter()
+        .filter(|comparison| match days {
+            DaySelection::All => true,
+            DaySelection::Day(day) => comparison.current.day == day,
+        })
+        .collect();
+
+    if comparisons.is_empty() {
+        println!("Nothing to compare, record runs with aoc run <day|all> --record");
+        return ExitCode::SUCCESS;
+    }
+
+    let mut regressions = 0;
+
+    for comparison in comparisons.iter() {
+        let (baseline, current) = (comparison.baseline, comparison.current);
+        let regressed = comparison.is_regression(threshold);
+
+        if regressed {
+            regressions += 1;
+        }
+
+        println!(
+            "Day {:02}, file {}, part {}: {} ({}) -> {} ({}), {:+.1}%{}",
+            current.day,
+            current.file,
+            current.part,
+            format_duration(baseline.solve_median),
+            baseline.revision,
+            format_duration(current.solve_median),
+            current.revision,
+            comparison.change() * 100.0,
+            if regressed { " REGRESSION" } else { "" }
+        );
+    }
+
+    println!();
+    println!(
+        "{} compared, {} regressed by more than {}%",
+        comparisons.len(),
+        regressions,
+        threshold * 100.0
+    );
+
+    if regressions == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn fetch(day: DayNumber, base_url: Option<&str>) -> ExitCode {
+    let fetched = Site::from_env(base_url).and_then(|site| {
+        let (cached, downloaded) = site.fetch_input(day, Path::new(CACHE_DIR))?;
+
+        if downloaded {
+            println!("Downloaded {}", cached.display());
+        } else {
+            println!("Using cached {}", cached.display());
+        }
+
+        install_input(day, &cached)
+    });
+
+    match fetched {
+        Ok((path, true)) => {
+            println!("Saved as {}", path);
+            ExitCode::SUCCESS
+        }
+        Ok((path, false)) => {
+            println!("Already saved as {}", path);
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(
+    registry: &Registry,
+    day: DayNumber,
+    part: Part,
+    input: Option<&str>,
+    base_url: Option<&str>,
+) -> Result<Outcome, String> {
+    let entry = registry
+        .get(day)
+        .ok_or_else(|| format!("day {:02} is not registered", day))?;
+
+    let input = match input {
+        Some(input) => input.to_string(),
+        None => {
+            let cached = cached_input(day, Path::new(CACHE_DIR));
+
+            if !cached.exists() {
+                return Err(format!(
+                    "{} is missing, run aoc fetch {} first",
+                    cached.display(),
+                    day
+                ));
+            }
+
+            cached.to_string_lossy().to_string()
+        }
+    };
+
+    let options = RunOptions {
+        parts: match part {
+            Part::One => PartSelection::One,
+            Part::Two => PartSelection::Two,
+        },
+        ..Default::default()
+    };
+
+    let report = entry.run(&options, &[&input]);
+
+    if let Some(error) = report.errors.first() {
+        return Err(error.to_string());
+    }
+
+    let answer = &report.results.first().ok_or("no answer")?.answer;
+
+    let mut ledger = Ledger::load(LEDGER_FILE).map_err(|error| error.to_string())?;
+
+    ledger
+        .check(day, part, answer, now())
+        .map_err(|reason| format!("not submitting {}: {}", answer, reason))?;
+
+    let site = Site::from_env(base_url)?;
+
+    println!("Submitting {} for day {:02} part {}", answer, day, part);
+
+    let (outcome, wait) = parse_response(&site.submit_answer(day, part, answer)?);
+
+    ledger
+        .record(
+            LEDGER_FILE,
+            Attempt {
+                timestamp: now(),
+                day,
+                part,
+                outcome,
+                wait,
+                answer: answer.clone(),
+            },
+        )
+        .map_err(|error| format!("{}: {}", LEDGER_FILE, error))?;
+
+    match wait {
+        Some(wait) => println!(
+            "The answer is {}, wait {}s before the next one",
+            outcome,
+            wait.as_secs()
+        ),
+        None => println!("The answer is {}", outcome),
+    }
+
+    Ok(outcome)
+}
+
+/// Prints the shape of an input and warns about everything the parser of `day` does not expect.
+fn inspect(registry: &Registry, day: DayNumber, input: &str) -> ExitCode {
+    let Some(entry) = registry.get(day) else {
+        eprintln!("day {:02} is not registered", day);
+        return ExitCode::from(2);
+    };
+
+    let source = match Source::load(input) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let stats = InputStats::new(source.text());
+
+    println!("{}", source.name());
+
+    if let Err(error) = stats.write_report(&mut std::io::stdout().lock()) {
+        eprintln!("Failed to write the report: {}", error);
+        return ExitCode::FAILURE;
+    }
+
+    let warnings = stats.check(&entry.input_assumptions());
+
+    for warning in warnings.iter() {
+        println!("Warning: {}", warning);
+    }
+
+    if warnings.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// The input to show or export, `--input` or else the first default input of the day.
+fn picture_input(entry: &dyn Day, picture: &PictureOptions) -> Result<String, String> {
+    picture
+        .input
+        .clone()
+        .or_else(|| entry.default_inputs().into_iter().next())
+        .ok_or_else(|| format!("day {:02} has no inputs, use --input", entry.number()))
+}
+
+/// The parameters meant for the day, checked like those of `aoc run`.
+fn picture_params(entry: &dyn Day, picture: &PictureOptions) -> Result<Vec<ParamArg>, String> {
+    let options = RunOptions {
+        params: picture.params.clone(),
+        ..Default::default()
+    };
+    let specs = entry.params();
+
+    options.check_params(&[(entry.number(), specs.clone())])?;
+
+    Ok(options.for_day(entry.number(), &specs).params)
+}
+
+fn show(registry: &Registry, day: DayNumber, picture: &PictureOptions, plain: bool) -> ExitCode {
+    let Some(entry) = registry.get(day) else {
+        eprintln!("day {:02} is not registered", day);
+        return ExitCode::from(2);
+    };
+
+    let rendered = picture_input(entry, picture).and_then(|input| {
+        let params = picture_params(entry, picture)?;
+
+        entry
+            .render(picture.part, &params, &input)
+            .map_err(|error| error.to_string())?
+            .ok_or_else(|| format!("day {:02} cannot be shown", day))
+    });
+
+    let canvas = match rendered {
+        Ok(canvas) => canvas,
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(error) = canvas.write(&mut std::io::stdout().lock(), !plain && ansi_supported()) {
+        eprintln!("Failed to write the picture: {}", error);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Saves the picture of a day as an image, or with `every` its animation as frames in a directory.
+fn export(
+    entry: &dyn Day,
+    path: &str,
+    picture: &PictureOptions,
+    format: Option<ImageFormat>,
+    scale: usize,
+    every: Option<usize>,
+) -> Result<String, String> {
+    let input = picture_input(entry, picture)?;
+    let params = picture_params(entry, picture)?;
+    let path = Path::new(path);
+
+    let Some(every) = every else {
+        let format = format
+            .or_else(|| ImageFormat::from_path(path))
+            .ok_or("unknown image format, use --format")?;
+
+        let canvas = entry
+            .render(picture.part, &params, &input)
+            .map_err(|error| error.to_string())?
+            .ok_or_else(|| format!("day {:02} cannot be shown", entry.number()))?;
+
+        canvas
+            .save_image(path, format, scale)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+        return Ok(format!("Wrote {}", path.display()));
+    };
+
+    let mut frames = FrameWriter::new(path, format.unwrap_or(ImageFormat::Ppm), scale, every)
+        .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+    let last = entry
+        .animate(picture.part, &params, &input, &mut |canvas| {
+            frames.frame(canvas)
+        })
+        .map_err(|error| error.to_string())?
+        .ok_or_else(|| format!("day {:02} cannot be animated", entry.number()))?;
+
+    let written = frames
+        .finish(&last)
+        .map_err(|error| format!("{}: {}", path.display(), error))?;
+
+    Ok(format!("Wrote {} frames to {}", written, path.display()))
+}
+
+/// Entry point of the `aoc` binary.
+pub fn main(registry: &Registry) -> ExitCode {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, usage());
+            return ExitCode::from(2);
+        }
+    };
+
+    match command {
+        Command::Help => {
+            println!("{}", usage());
+            ExitCode::SUCCESS
+        }
+        Command::Run {
+            days,
+            inputs,
+            options,
+            record,
+            trace,
+        } => run(registry, days, &inputs, &options, record, &trace),
+        Command::Compare {
+            days,
+            base,
+            threshold,
+        } => compare_history(days, base.as_deref(), threshold),
+        Command::New { day } => match create_day(day) {
+            Ok(changed) => {
+                for path in changed {
+                    println!("Wrote {}", path);
+                }
+
+                ExitCode::SUCCESS
+            }
+            Err(message) => {
+                eprintln!("Error: {}", message);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Fetch { day, base_url } => fetch(day, base_url.as_deref()),
+        Command::Submit {
+            day,
+            part,
+            input,
+            base_url,
+        } => match submit(registry, day, part, input.as_deref(), base_url.as_deref()) {
+            Ok(Outcome::Correct) => ExitCode::SUCCESS,
+            Ok(_) => ExitCode::FAILURE,
+            Err(message) => {
+                eprintln!("Error: {}", message);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Watch { day, args } => watch(day, &args),
+        Command::Inspect { day, input } => inspect(registry, day, &input),
+        Command::Show {
+            day,
+            picture,
+            plain,
+        } => show(registry, day, &picture, plain),
+        Command::Export {
+            day,
+            path,
+            picture,
+            format,
+            scale,
+            frames,
+            every,
+        } => {
+            let Some(entry) = registry.get(day) else {
+                eprintln!("day {:02} is not registered", day);
+                return ExitCode::from(2);
+            };
+
+            match export(
+                entry,
+                &path,
+                &picture,
+                format,
+                scale,
+                frames.then_some(every),
+            ) {
+                Ok(message) => {
+                    println!("{}", message);
+                    ExitCode::SUCCESS
+                }
+                Err(message) => {
+                    eprintln!("Error: {}", message);
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parse_run() {
+    let args = ["run", "05", "--input", "example.txt", "--part", "1"].map(String::from);
+
+    match Command::parse(args).unwrap() {
+        Command::Run {
+            days,
+            inputs,
+            options,
+            record,
+            trace,
+        } => {
+            assert!(days == DaySelection::Day(5));
+            assert!(trace.is_empty());
+            assert!(!record);
+            assert!(inputs == ["example.txt"]);
+            assert!(options.parts == crate::PartSelection::One);
+            assert!(options.jobs == 1);
+        }
+        _ => panic!("expected a run command"),
+    }
+}
+
+#[test]
+fn test_parse_invalid_run() {
+    assert!(Command::parse(["run", "26"].map(String::from)).is_err());
+    assert!(Command::parse(["run", "all", "--input", "x"].map(String::from)).is_err());
+    assert!(Command::parse(["run", "1", "--bogus"].map(String::from)).is_err());
+    assert!(Command::parse(["run", "1", "--record", "--jobs", "2"].map(String::from)).is_err());
+    assert!(
+        Command::parse(["run", "11", "--record", "--param", "blinks=6"].map(String::from)).is_err()
+    );
+    assert!(Command::parse(["watch", "all"].map(String::from)).is_err());
+    assert!(Command::parse(["fetch", "3", "--base-url"].map(String::from)).is_err());
+    assert!(Command::parse(["submit", "3"].map(String::from)).is_err());
+    assert!(Command::parse(["compare", "all", "--threshold", "-5"].map(String::from)).is_err());
+    assert!(Command::parse(["watch", "1", "--format", "csv"].map(String::from)).is_err());
+    assert!(Command::parse(["inspect", "6"].map(String::from)).is_err());
+    assert!(Command::parse(["show", "6", "--part", "both"].map(String::from)).is_err());
+    assert!(Command::parse(["export", "6"].map(String::from)).is_err());
+    assert!(Command::parse(["export", "6", "walk.ppm", "--every", "5"].map(String::from)).is_err());
+    assert!(Command::parse(["export", "6", "walk.ppm", "--scale", "0"].map(String::from)).is_err());
+}
@@ -0,0 +1,269 @@
+use std::{fmt::Debug, str::FromStr, sync::Arc, time::Instant};
+
+use crate::{registry::DayNumber, Params, Part};
+
+/// Verbosity of trace events, a level enables all less verbose levels too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TraceLevel {
+    Info,
+    Debug,
+    Trace,
+}
+
+impl TraceLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TraceLevel::Info => "info",
+            TraceLevel::Debug => "debug",
+            TraceLevel::Trace => "trace",
+        }
+    }
+}
+
+impl FromStr for TraceLevel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "info" => Ok(TraceLevel::Info),
+            "debug" => Ok(TraceLevel::Debug),
+            "trace" => Ok(TraceLevel::Trace),
+            _ => Err(format!(
+                "invalid trace level {:?}, expected info, debug or trace",
+                value
+            )),
+        }
+    }
+}
+
+/// `--trace LEVEL` traces every selected day, `--trace DAY=LEVEL` only that day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceFilter {
+    pub day: Option<DayNumber>,
+    pub level: TraceLevel,
+}
+
+impl TraceFilter {
+    /// The most verbose level enabled for `day` by any of the filters.
+    pub fn level_for(filters: &[TraceFilter], day: DayNumber) -> Option<TraceLevel> {
+        filters
+            .iter()
+            .filter(|filter| filter.day.is_none_or(|filter_day| filter_day == day))
+            .map(|filter| filter.level)
+            .max()
+    }
+}
+
+impl FromStr for TraceFilter {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.split_once('=') {
+            None => Ok(TraceFilter {
+                day: None,
+                level: value.parse()?,
+            }),
+            Some((day, level)) => match day.parse() {
+                Ok(day) if (1..=25).contains(&day) => Ok(TraceFilter {
+                    day: Some(day),
+                    level: level.parse()?,
+                }),
+                _ => Err(format!("invalid day {:?}, expected 1 to 25", day)),
+            },
+        }
+    }
+}
+
+pub struct TraceEvent<'a> {
+    pub level: TraceLevel,
+    pub file: &'a str,
+    pub part: Part,
+    pub message: String,
+}
+
+/// Receives the trace events of solvers, observers are shared by all threads of a run.
+pub trait Observer: Send + Sync {
+    fn event(&self, event: &TraceEvent);
+}
+
+/// Prints every event to stderr, so that it does not mix with the results.
+pub struct StderrObserver;
+
+impl Observer for StderrObserver {
+    fn event(&self, event: &TraceEvent) {
+        eprintln!(
+            "[{} part {} {}] {}",
+            event.file,
+            event.part,
+            event.level.name(),
+            event.message
+        );
+    }
+}
+
+/// An observer with the most verbose level it wants to receive.
+#[derive(Clone)]
+pub struct Tracer {
+    pub level: TraceLevel,
+    pub observer: Arc<dyn Observer>,
+}
+
+impl Tracer {
+    pub fn new(level: TraceLevel, observer: impl Observer + 'static) -> Self {
+        Tracer {
+            level,
+            observer: Arc::new(observer),
+        }
+    }
+}
+
+impl Debug for Tracer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tracer")
+            .field("level", &self.level)
+            .finish()
+    }
+}
+
+/// Passed to solvers to report what they are doing.
+/// The messages are closures that are only called when their level is enabled,
+/// so tracing costs a single branch when it is disabled.
+pub struct Context<'a> {
+    tracer: Option<&'a Tracer>,
+    params: &'a Params,
+    file: &'a str,
+    part: Part,
+    deadline: Option<Instant>,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(tracer: Option<&'a Tracer>, params: &'a Params, file: &'a str, part: Part) -> Self {
+        Context {
+            tracer,
+            params,
+            file,
+            part,
+            deadline: None,
+        }
+    }
+
+    /// Cancels the solve once `deadline` has passed, see `is_cancelled`.
+    pub fn with_deadline(self, deadline: Option<Instant>) -> Self {
+        Context { deadline, ..self }
+    }
+
+    /// A context without tracing and parameters, e.g. for tests.
+    pub fn disabled(part: Part) -> Self {
+        static NO_PARAMS: Params = Params::empty();
+
+        Context::new(None, &NO_PARAMS, "", part)
+    }
+
+    pub fn part(&self) -> Part {
+        self.part
+    }
+
+    pub fn params(&self) -> &Params {
+        self.params
+    }
+
+    /// Whether the solver ran out of time. Long running solvers should poll it
+    /// between units of work and return early, their answer is discarded anyway.
+    pub fn is_cancelled(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    #[inline]
+    pub fn is_enabled(&self, level: TraceLevel) -> bool {
+        self.tracer.is_some_and(|tracer| level <= tracer.level)
+    }
+
+    #[inline]
+    pub fn event(&self, level: TraceLevel, message: impl FnOnce() -> String) {
+        if let Some(tracer) = self.tracer.filter(|tracer| level <= tracer.level) {
+            tracer.observer.event(&TraceEvent {
+                level,
+                file: self.file,
+                part: self.part,
+                message: message(),
+            });
+        }
+    }
+
+    #[inline]
+    pub fn info(&self, message: impl FnOnce() -> String) {
+        self.event(TraceLevel::Info, message);
+    }
+
+    #[inline]
+    pub fn debug(&self, message: impl FnOnce() -> String) {
+        self.event(TraceLevel::Debug, message);
+    }
+
+    #[inline]
+    pub fn trace(&self, message: impl FnOnce() -> String) {
+        self.event(TraceLevel::Trace, message);
+    }
+}
+
+#[cfg(test)]
+struct CollectingObserver {
+    events: std::sync::Mutex<Vec<String>>,
+}
+
+#[cfg(test)]
+impl Observer for CollectingObserver {
+    fn event(&self, event: &TraceEvent) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", event.level.name(), event.message));
+    }
+}
+
+#[test]
+fn test_trace_levels() {
+    let observer = Arc::new(CollectingObserver {
+        events: Default::default(),
+    });
+    let tracer = Tracer {
+        level: TraceLevel::Debug,
+        observer: observer.clone(),
+    };
+    let params = Params::default();
+    let context = Context::new(Some(&tracer), &params, "input.txt", Part::One);
+
+    context.info(|| String::from("a"));
+    context.debug(|| String::from("b"));
+    context.trace(|| panic!("disabled messages are never built"));
+
+    assert!(*observer.events.lock().unwrap() == ["info a", "debug b"]);
+    assert!(!Context::disabled(Part::One).is_enabled(TraceLevel::Info));
+}
+
+#[test]
+fn test_cancellation() {
+    let now = Instant::now();
+
+    assert!(!Context::disabled(Part::One).is_cancelled());
+    assert!(Context::disabled(Part::One)
+        .with_deadline(Some(now))
+        .is_cancelled());
+    assert!(!Context::disabled(Part::One)
+        .with_deadline(Some(now + std::time::Duration::from_secs(60)))
+        .is_cancelled());
+}
+
+#[test]
+fn test_trace_filters() {
+    let filters: Vec<TraceFilter> = ["info", "6=trace"]
+        .iter()
+        .map(|filter| filter.parse().unwrap())
+        .collect();
+
+    assert!(TraceFilter::level_for(&filters, 6) == Some(TraceLevel::Trace));
+    assert!(TraceFilter::level_for(&filters, 9) == Some(TraceLevel::Info));
+    assert!(TraceFilter::level_for(&filters[1..], 9).is_none());
+    assert!("26=info".parse::<TraceFilter>().is_err());
+}
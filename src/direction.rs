@@ -0,0 +1,145 @@
+use std::ops::Neg;
+
+use crate::Offset;
+
+/// One of the eight directions on a grid.
+/// Rows are counted from the top, so `Up` decreases `y` and `Down` increases it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Clockwise starting with `Up`.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Clockwise starting with `Up`, the diagonals in between the cardinal directions.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Parses the arrows `^`, `>`, `v` and `<`.
+    pub fn from_char(char: char) -> Option<Direction> {
+        match char {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The arrow of a cardinal direction, diagonals are drawn as `/` and `\\`.
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::UpRight | Direction::DownLeft => '/',
+            Direction::DownRight | Direction::UpLeft => '\\',
+        }
+    }
+
+    pub const fn offset(self) -> Offset {
+        match self {
+            Direction::Up => Offset::new(0, -1),
+            Direction::UpRight => Offset::new(1, -1),
+            Direction::Right => Offset::new(1, 0),
+            Direction::DownRight => Offset::new(1, 1),
+            Direction::Down => Offset::new(0, 1),
+            Direction::DownLeft => Offset::new(-1, 1),
+            Direction::Left => Offset::new(-1, 0),
+            Direction::UpLeft => Offset::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Direction::CARDINAL.contains(&self)
+    }
+
+    // eighths of a full turn clockwise
+    fn turn(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % Direction::ALL.len()]
+    }
+
+    /// Turns by 90 degrees clockwise.
+    pub fn rotate_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    /// Turns by 90 degrees counterclockwise.
+    pub fn rotate_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn(4)
+    }
+}
+
+impl Neg for Direction {
+    type Output = Direction;
+
+    fn neg(self) -> Direction {
+        self.reverse()
+    }
+}
+
+impl From<Direction> for Offset {
+    fn from(direction: Direction) -> Offset {
+        direction.offset()
+    }
+}
+
+#[test]
+fn test_rotate() {
+    assert!(Direction::Up.rotate_right() == Direction::Right);
+    assert!(Direction::Up.rotate_left() == Direction::Left);
+    assert!(Direction::UpLeft.rotate_right() == Direction::UpRight);
+    assert!(Direction::DownRight.reverse() == Direction::UpLeft);
+
+    for direction in Direction::ALL {
+        assert!(direction.rotate_right().rotate_left() == direction);
+        assert!(direction.offset() == -direction.reverse().offset());
+    }
+}
+
+#[test]
+fn test_from_char() {
+    let directions: Vec<Option<Direction>> = "^>v<x".chars().map(Direction::from_char).collect();
+
+    assert!(
+        directions
+            == [
+                Some(Direction::Up),
+                Some(Direction::Right),
+                Some(Direction::Down),
+                Some(Direction::Left),
+                None
+            ]
+    );
+    assert!(Direction::CARDINAL
+        .iter()
+        .all(|direction| Direction::from_char(direction.to_char()) == Some(*direction)));
+    assert!(Direction::Up.offset().y < 0);
+}
@@ -0,0 +1,303 @@
+use std::{
+    fs::{create_dir_all, read_dir, remove_file, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{Canvas, Cell};
+
+// colors of the cells without any, like on a dark terminal
+const BACKGROUND: [u8; 3] = [24, 24, 24];
+const FOREGROUND: [u8; 3] = [176, 176, 176];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Svg,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Svg => "svg",
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        path.extension()?.to_str()?.parse().ok()
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "svg" => Ok(ImageFormat::Svg),
+            _ => Err(format!(
+                "invalid image format {:?}, expected ppm or svg",
+                value
+            )),
+        }
+    }
+}
+
+// a pixel image has no room for characters, so a cell is filled with its tint or its color
+fn fill(cell: &Cell) -> [u8; 3] {
+    match cell.tint.or(cell.color) {
+        Some(color) => color.rgb(),
+        None if cell.char == '.' || cell.char == ' ' => BACKGROUND,
+        None => FOREGROUND,
+    }
+}
+
+fn hex([r, g, b]: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+impl Canvas {
+    /// Writes a binary PPM with squares of `scale` pixels per cell.
+    pub fn write_ppm(&self, out: &mut impl Write, scale: usize) -> io::Result<()> {
+        let cells = self.cells();
+
+        write!(
+            out,
+            "P6\n{} {}\n255\n",
+            cells.width() * scale,
+            cells.height() * scale
+        )?;
+
+        for row in cells.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| fill(cell).repeat(scale))
+                .collect();
+
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes an SVG with the characters on top of the tints, `scale` pixels per cell.
+    pub fn write_svg(&self, out: &mut impl Write, scale: usize) -> io::Result<()> {
+        let cells = self.cells();
+        let (width, height) = (cells.width(), cells.height());
+
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" font-family=\"monospace\" font-size=\"0.9\" text-anchor=\"middle\">",
+            width * scale,
+            height * scale,
+            width,
+            height
+        )?;
+        writeln!(
+            out,
+            "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            width,
+            height,
+            hex(BACKGROUND)
+        )?;
+
+        for (position, cell) in cells.iter() {
+            if let Some(tint) = cell.tint {
+                writeln!(
+                    out,
+                    "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"{}\"/>",
+                    position.x,
+                    position.y,
+                    hex(tint.rgb())
+                )?;
+            }
+
+            let text = match cell.char {
+                ' ' => continue,
+                '&' => String::from("&amp;"),
+                '<' => String::from("&lt;"),
+                '>' => String::from("&gt;"),
+                char => char.to_string(),
+            };
+
+            writeln!(
+                out,
+                "<text x=\"{}.5\" y=\"{}.8\" fill=\"{}\">{}</text>",
+                position.x,
+                position.y,
+                hex(cell.color.map_or(FOREGROUND, |color| color.rgb())),
+                text
+            )?;
+        }
+
+        writeln!(out, "</svg>")
+    }
+
+    pub fn write_image(
+        &self,
+        out: &mut impl Write,
+        format: ImageFormat,
+        scale: usize,
+    ) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(out, scale),
+            ImageFormat::Svg => self.write_svg(out, scale),
+        }
+    }
+
+    pub fn save_image(&self, path: &Path, format: ImageFormat, scale: usize) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+
+        self.write_image(&mut out, format, scale)?;
+        out.flush()
+    }
+}
+
+/// Saves every `every`th frame of an animation as `frame_00000.ppm`, `frame_00001.ppm`, ...
+/// numbered without gaps so that they can be passed to e.g. ffmpeg as they are.
+/// The frames of an earlier export to the same directory are deleted first, so that a shorter
+/// animation doesn't end in stale ones.
+pub struct FrameWriter {
+    dir: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    every: usize,
+    // frames received, including the skipped ones
+    received: usize,
+    written: usize,
+    last_saved: bool,
+    // the first error, the remaining frames are dropped after it
+    error: Option<io::Error>,
+}
+
+impl FrameWriter {
+    pub fn new(dir: &Path, format: ImageFormat, scale: usize, every: usize) -> io::Result<Self> {
+        create_dir_all(dir)?;
+
+        for entry in read_dir(dir)? {
+            let path = entry?.path();
+
+            if is_frame(&path, format) {
+                remove_file(path)?;
+            }
+        }
+
+        Ok(FrameWriter {
+            dir: dir.to_path_buf(),
+            format,
+            scale,
+            every: every.max(1),
+            received: 0,
+            written: 0,
+            last_saved: false,
+            error: None,
+        })
+    }
+
+    pub fn frame(&mut self, canvas: &Canvas) {
+        self.last_saved = self.received.is_multiple_of(self.every);
+        self.received += 1;
+
+        if self.last_saved && self.error.is_none() {
+            self.save(canvas);
+        }
+    }
+
+    /// Saves `last` too if it was skipped, so that an animation always shows the end.
+    /// Returns the number of frames written.
+    pub fn finish(mut self, last: &Canvas) -> io::Result<usize> {
+        if !self.last_saved && self.error.is_none() {
+            self.save(last);
+        }
+
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.written),
+        }
+    }
+
+    fn save(&mut self, canvas: &Canvas) {
+        let path = self.dir.join(format!(
+            "frame_{:05}.{}",
+            self.written,
+            self.format.extension()
+        ));
+
+        match canvas.save_image(&path, self.format, self.scale) {
+            Ok(()) => self.written += 1,
+            Err(error) => self.error = Some(error),
+        }
+    }
+}
+
+fn is_frame(path: &Path, format: ImageFormat) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+
+    name.strip_prefix("frame_")
+        .and_then(|name| name.strip_suffix(&format!(".{}", format.extension())))
+        .is_some_and(|number| {
+            !number.is_empty() && number.bytes().all(|byte| byte.is_ascii_digit())
+        })
+}
+
+#[test]
+fn test_write_ppm() {
+    let mut canvas = crate::render::example_canvas();
+    let mut out = Vec::new();
+
+    canvas.mark(crate::Position::new(1, 0), '<', crate::Color::Red);
+    canvas.write_ppm(&mut out, 2).unwrap();
+
+    let (header, pixels) = out.split_at(11);
+    let red = crate::Color::Red.rgb();
+
+    assert!(header == b"P6\n6 4\n255\n");
+    assert!(pixels.len() == 6 * 4 * 3);
+    assert!(pixels[..6] == [BACKGROUND, BACKGROUND].concat());
+    assert!(pixels[6..12] == [red, red].concat());
+}
+
+#[test]
+fn test_write_svg() {
+    let mut canvas = crate::render::example_canvas();
+    let mut out = Vec::new();
+
+    canvas.mark(crate::Position::new(1, 0), '<', crate::Color::Red);
+    canvas.write_svg(&mut out, 10).unwrap();
+
+    let svg = String::from_utf8(out).unwrap();
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"20\""));
+    assert!(svg.contains("<text x=\"1.5\" y=\"0.8\" fill=\"#cd3131\">&lt;</text>"));
+    assert!(svg.ends_with("</svg>\n"));
+}
+
+#[test]
+fn test_frame_writer() {
+    let dir = crate::testing::temp_dir("frames");
+    let canvas = crate::render::example_canvas();
+
+    // left over from a longer animation
+    create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("frame_00003.svg"), "").unwrap();
+    std::fs::write(dir.join("walk.svg"), "").unwrap();
+
+    let mut frames = FrameWriter::new(&dir, ImageFormat::Svg, 1, 2).unwrap();
+
+    for _ in 0..4 {
+        frames.frame(&canvas);
+    }
+
+    // frames 0 and 2, and the last one which was skipped
+    assert!(frames.finish(&canvas).unwrap() == 3);
+    assert!(dir.join("frame_00002.svg").exists());
+    assert!(!dir.join("frame_00003.svg").exists());
+    assert!(dir.join("walk.svg").exists());
+    assert!(ImageFormat::from_path(Path::new("walk.ppm")) == Some(ImageFormat::Ppm));
+}
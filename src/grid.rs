@@ -0,0 +1,256 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction, Offset, ParseError, Position, Source};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            panic!("{} cells do not make rows of {}", cells.len(), width);
+        }
+
+        Grid {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    /// Parses one cell per character, every line is a row.
+    /// `cell` returns the message of the error for characters it does not expect.
+    pub fn parse(
+        source: &Source,
+        mut cell: impl FnMut(Position, char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let width = source.first_line()?.chars().count();
+
+        if width == 0 {
+            return Err(source.error(source.text(), "grid is empty"));
+        }
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (y, line) in source.lines().enumerate() {
+            let mut x = 0;
+
+            for (idx, char) in line.char_indices() {
+                if x == width {
+                    return Err(source.char_error(
+                        line,
+                        idx,
+                        format!("expected rows of {} characters", width),
+                    ));
+                }
+
+                cells.push(
+                    cell(Position { x, y }, char)
+                        .map_err(|message| source.char_error(line, idx, message))?,
+                );
+                x += 1;
+            }
+
+            if x < width {
+                return Err(source.error(line, format!("expected rows of {} characters", width)));
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    fn idx(&self, position: Position) -> Option<usize> {
+        // lazily, the multiplication can overflow for positions far outside
+        self.contains(position)
+            .then(|| position.x + position.y * self.width)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.idx(position).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.idx(position).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Panics if `x` is outside of the grid, like `row` does for `y`.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside of a grid {} wide",
+            x,
+            self.width
+        );
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// All positions row by row, starting at the top left.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Moves `position` by `offset` (or one cell in a `Direction`),
+    /// or returns `None` if that leaves the grid.
+    pub fn step(&self, position: Position, offset: impl Into<Offset>) -> Option<Position> {
+        position
+            .checked_add(offset.into())
+            .filter(|position| self.contains(*position))
+    }
+
+    /// The horizontal and vertical neighbors within the grid, clockwise starting above.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Like `neighbors4` but with the diagonal neighbors too.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "{},{} is outside of the {}x{} grid",
+                position.x, position.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "{},{} is outside of the {}x{} grid",
+                position.x, position.y, width, height
+            ),
+        }
+    }
+}
+
+#[test]
+fn test_parse_grid() {
+    let grid = Grid::parse(&Source::new("example", "123\n456\n"), |_, char| {
+        char.to_digit(10).ok_or("expected a digit")
+    })
+    .unwrap();
+
+    assert!(grid.width() == 3 && grid.height() == 2);
+    assert!(grid[Position::new(2, 1)] == 6);
+    assert!(grid.get(Position::new(3, 0)).is_none());
+    assert!(grid.get(Position::new(1, usize::MAX)).is_none());
+    assert!(grid.row(1) == [4, 5, 6]);
+    assert!(grid.column(1).copied().collect::<Vec<u32>>() == [2, 5]);
+    assert!(grid.rows().count() == 2);
+    assert!(grid.iter().nth(4) == Some((Position::new(1, 1), &5)));
+
+    let uneven = Grid::parse(&Source::new("uneven", "12\n1\n"), |_, char| Ok(char));
+    let unknown = Grid::parse(&Source::new("unknown", "1x\n"), |_, char| {
+        char.to_digit(10).ok_or("expected a digit")
+    });
+
+    assert!(
+        uneven.unwrap_err().to_string()
+            == "uneven:2:1: expected rows of 2 characters (found \"1\")"
+    );
+    assert!(unknown.unwrap_err().to_string() == "unknown:1:2: expected a digit (found \"x\")");
+    assert!(Grid::parse(&Source::new("empty", ""), |_, char| Ok(char)).is_err());
+}
+
+#[test]
+fn test_step() {
+    let grid = Grid::new(3, 2, 0);
+
+    assert!(grid.step(Position::new(0, 0), Offset::new(2, 1)) == Some(Position::new(2, 1)));
+    assert!(grid.step(Position::new(0, 0), Offset::new(-1, 0)).is_none());
+    assert!(grid.step(Position::new(2, 1), Offset::new(1, 0)).is_none());
+    assert!(grid.step(Position::new(2, 1), Direction::Up) == Some(Position::new(2, 0)));
+}
+
+#[test]
+fn test_neighbors() {
+    let grid = Grid::parse(&Source::new("example", "123\n456\n"), |_, char| {
+        char.to_digit(10).ok_or("expected a digit")
+    })
+    .unwrap();
+
+    let corner: Vec<Position> = grid.neighbors4(Position::new(0, 0)).collect();
+    let all: Vec<u32> = grid
+        .neighbors8(Position::new(1, 0))
+        .map(|position| grid[position])
+        .collect();
+
+    assert!(corner == [Position::new(1, 0), Position::new(0, 1)]);
+    assert!(all == [3, 6, 5, 4, 1]);
+}
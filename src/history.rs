@@ -0,0 +1,296 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    records::{append_records, load_records, parse_records},
+    registry::DayNumber,
+    ParseError, Part, RunReport, Source, Status,
+};
+
+/// Benchmark history of `aoc run --record`, relative to the repository root.
+pub const HISTORY_FILE: &str = ".aoc/history.txt";
+
+const HISTORY_HEADER: &str = "# timestamp\trevision\tday\tpart\tinput_hash\trepeat\tparse_median_ns\tsolve_min_ns\tsolve_median_ns\tsolve_max_ns\tfile";
+
+/// 64 bit FNV-1a hash of an input, so that timings of different inputs for the same file name are never compared.
+pub fn input_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The current git revision, marked as dirty when there are uncommitted changes.
+pub fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match (
+        git(&["rev-parse", "--short", "HEAD"]),
+        git(&["status", "--porcelain", "--untracked-files=no"]),
+    ) {
+        (Some(revision), Some(status)) if !status.is_empty() => format!("{}-dirty", revision),
+        (Some(revision), _) => revision,
+        (None, _) => String::from("unknown"),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry {
+    // seconds since the unix epoch
+    pub timestamp: u64,
+    pub revision: String,
+    pub day: DayNumber,
+    pub part: Part,
+    pub input_hash: u64,
+    pub repeat: usize,
+    pub parse_median: Duration,
+    pub solve_min: Duration,
+    pub solve_median: Duration,
+    pub solve_max: Duration,
+    pub file: String,
+}
+
+impl HistoryEntry {
+    /// One entry per part result of a day's report.
+    pub fn from_report(
+        day: DayNumber,
+        report: &RunReport,
+        revision: &str,
+        repeat: usize,
+    ) -> Vec<Self> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        // the timings of a solve that timed out are not comparable
+        report
+            .results
+            .iter()
+            .filter(|result| result.status != Status::Timeout)
+            .map(|result| HistoryEntry {
+                timestamp,
+                revision: revision.to_string(),
+                day,
+                part: result.part,
+                input_hash: result.input_hash,
+                repeat,
+                parse_median: result.parse_time.median(),
+                solve_min: result.solve_time.min(),
+                solve_median: result.solve_time.median(),
+                solve_max: result.solve_time.max(),
+                file: result.file.clone(),
+            })
+            .collect()
+    }
+
+    fn parse(source: &Source, line: &str) -> Result<Self, ParseError> {
+        let fields: Vec<&str> = line.splitn(11, '\t').collect();
+
+        let [timestamp, revision, day, part, input_hash, repeat, parse_median, solve_min, solve_median, solve_max, file] =
+            fields[..]
+        else {
+            return Err(source.error(line, "expected 11 tab separated fields"));
+        };
+
+        let nanos = |field: &str| source.parse::<u64>(field).map(Duration::from_nanos);
+
+        Ok(HistoryEntry {
+            timestamp: source.parse(timestamp)?,
+            revision: revision.to_string(),
+            day: source.parse(day)?,
+            part: source.parse(part)?,
+            input_hash: u64::from_str_radix(input_hash, 16)
+                .map_err(|error| source.error(input_hash, error))?,
+            repeat: source.parse(repeat)?,
+            parse_median: nanos(parse_median)?,
+            solve_min: nanos(solve_min)?,
+            solve_median: nanos(solve_median)?,
+            solve_max: nanos(solve_max)?,
+            file: file.to_string(),
+        })
+    }
+
+    fn key(&self) -> (DayNumber, Part, u64) {
+        (self.day, self.part, self.input_hash)
+    }
+}
+
+impl Display for HistoryEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{:016x}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.revision,
+            self.day,
+            self.part,
+            self.input_hash,
+            self.repeat,
+            self.parse_median.as_nanos(),
+            self.solve_min.as_nanos(),
+            self.solve_median.as_nanos(),
+            self.solve_max.as_nanos(),
+            self.file
+        )
+    }
+}
+
+/// Parses a history file, `#` comments and empty lines are ignored.
+pub fn parse_history(source: &Source) -> Result<Vec<HistoryEntry>, ParseError> {
+    parse_records(source, HistoryEntry::parse)
+}
+
+/// Loads the history, a missing history file is the same as an empty one.
+pub fn load_history(history_file: &str) -> Result<Vec<HistoryEntry>, ParseError> {
+    load_records(history_file, HistoryEntry::parse)
+}
+
+pub fn append_history(history_file: &str, entries: &[HistoryEntry]) -> std::io::Result<()> {
+    append_records(history_file, HISTORY_HEADER, entries)
+}
+
+/// The latest timing of a solver compared to an older one.
+#[derive(Debug)]
+pub struct Comparison<'a> {
+    pub baseline: &'a HistoryEntry,
+    pub current: &'a HistoryEntry,
+}
+
+impl Comparison<'_> {
+    /// Relative change of the median solve time, 0.1 means 10% slower.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.solve_median.as_secs_f64();
+
+        if baseline == 0.0 {
+            return 0.0;
+        }
+
+        self.current.solve_median.as_secs_f64() / baseline - 1.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares the latest entry of every day, part and input with the latest older entry of an other revision,
+/// or of `base_revision` if given. Solvers without such an entry are left out.
+pub fn compare<'a>(
+    history: &'a [HistoryEntry],
+    base_revision: Option<&str>,
+) -> Vec<Comparison<'a>> {
+    let mut by_key: BTreeMap<(DayNumber, Part, u64), Vec<&HistoryEntry>> = BTreeMap::new();
+
+    for entry in history {
+        by_key.entry(entry.key()).or_default().push(entry);
+    }
+
+    by_key
+        .into_values()
+        .filter_map(|entries| {
+            // entries are appended, so the file order is also the time order
+            let current = *entries.last()?;
+
+            let baseline = entries
+                .iter()
+                .rev()
+                .skip(1)
+                .find(|entry| match base_revision {
+                    Some(base_revision) => entry.revision == base_revision,
+                    None => entry.revision != current.revision,
+                })?;
+
+            Some(Comparison { baseline, current })
+        })
+        .collect()
+}
+
+#[test]
+fn test_input_hash() {
+    assert!(input_hash("") == 0xcbf29ce484222325);
+    assert!(input_hash("a") == 0xaf63dc4c8601ec8c);
+}
+
+#[test]
+fn test_parse_history() {
+    let entry = HistoryEntry {
+        timestamp: 1733000000,
+        revision: String::from("abc1234"),
+        day: 6,
+        part: Part::Two,
+        input_hash: input_hash("..#.\n"),
+        repeat: 3,
+        parse_median: Duration::from_micros(5),
+        solve_min: Duration::from_millis(99),
+        solve_median: Duration::from_millis(100),
+        solve_max: Duration::from_millis(101),
+        file: String::from("src/06/input 2.txt"),
+    };
+    let entries = vec![
+        entry.clone(),
+        HistoryEntry {
+            revision: String::from("def5678"),
+            ..entry
+        },
+    ];
+    let text: String = entries.iter().map(|entry| format!("{}\n", entry)).collect();
+
+    let source = Source::new(HISTORY_FILE, &format!("{}\n{}", HISTORY_HEADER, text));
+
+    assert!(parse_history(&source).unwrap() == entries);
+    assert!(parse_history(&Source::new(HISTORY_FILE, "1\tabc\n")).is_err());
+}
+
+#[test]
+fn test_compare() {
+    let entry = HistoryEntry {
+        timestamp: 1733000000,
+        revision: String::from("abc1234"),
+        day: 6,
+        part: Part::Two,
+        input_hash: 0,
+        repeat: 1,
+        parse_median: Duration::ZERO,
+        solve_min: Duration::ZERO,
+        solve_median: Duration::from_millis(100),
+        solve_max: Duration::ZERO,
+        file: String::from("src/06/input_2.txt"),
+    };
+    let history = vec![
+        entry.clone(),
+        HistoryEntry {
+            revision: String::from("def5678"),
+            solve_median: Duration::from_millis(120),
+            ..entry.clone()
+        },
+        HistoryEntry {
+            revision: String::from("def5678"),
+            solve_median: Duration::from_millis(130),
+            ..entry
+        },
+    ];
+
+    let comparisons = compare(&history, None);
+
+    assert!(comparisons.len() == 1);
+    assert!(comparisons[0].baseline.solve_median == Duration::from_millis(100));
+    assert!(comparisons[0].current.solve_median == Duration::from_millis(130));
+    assert!(comparisons[0].is_regression(0.1));
+    assert!(!comparisons[0].is_regression(0.5));
+
+    let comparisons = compare(&history, Some("def5678"));
+
+    assert!(comparisons[0].baseline.solve_median == Duration::from_millis(120));
+    assert!(compare(&history, Some("unknown")).is_empty());
+    assert!(compare(&history[..1], None).is_empty());
+}
@@ -0,0 +1,278 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
+
+/// Something a parser relies on without checking it, reported by `aoc inspect` when an input breaks it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputAssumption {
+    Ascii,
+    // all rows are as long as the first one, e.g. the rows of a grid
+    Rectangular,
+    // the only characters besides line breaks
+    Chars(&'static str),
+    Lines(usize),
+    // groups of lines separated by blank lines
+    Sections(usize),
+}
+
+/// Lines are numbered from 1, like in parse errors.
+#[derive(Debug, Default)]
+pub struct InputStats {
+    pub lines: usize,
+    pub ends_with_newline: bool,
+    pub crlf_lines: usize,
+    // number of non-blank rows of each length, in characters
+    pub row_lengths: BTreeMap<usize, usize>,
+    pub first_row_length: Option<usize>,
+    pub chars: BTreeMap<char, usize>,
+    pub trailing_whitespace: Vec<usize>,
+    // first and last line of every group of non-blank lines
+    pub sections: Vec<(usize, usize)>,
+}
+
+impl InputStats {
+    pub fn new(text: &str) -> Self {
+        let mut stats = InputStats {
+            ends_with_newline: text.ends_with('\n'),
+            ..Default::default()
+        };
+
+        let mut section_start = None;
+
+        // the same lines as `str::lines`, which also strips the `\r` of a `\r\n`
+        for (idx, line) in text.split_inclusive('\n').enumerate() {
+            let number = idx + 1;
+
+            stats.lines = number;
+
+            let line = match line.strip_suffix("\r\n") {
+                Some(line) => {
+                    stats.crlf_lines += 1;
+                    line
+                }
+                None => line.strip_suffix('\n').unwrap_or(line),
+            };
+
+            if line.ends_with(char::is_whitespace) {
+                stats.trailing_whitespace.push(number);
+            }
+
+            for char in line.chars() {
+                *stats.chars.entry(char).or_default() += 1;
+            }
+
+            if line.is_empty() {
+                if let Some(start) = section_start.take() {
+                    stats.sections.push((start, number - 1));
+                }
+
+                continue;
+            }
+
+            let length = line.chars().count();
+
+            stats.first_row_length.get_or_insert(length);
+            *stats.row_lengths.entry(length).or_default() += 1;
+            section_start.get_or_insert(number);
+        }
+
+        if let Some(start) = section_start {
+            stats.sections.push((start, stats.lines));
+        }
+
+        stats
+    }
+
+    /// A warning for every assumption the input does not hold up to.
+    pub fn check(&self, assumptions: &[InputAssumption]) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        for assumption in assumptions {
+            match *assumption {
+                InputAssumption::Ascii => {
+                    let unexpected: Vec<char> = self
+                        .chars
+                        .keys()
+                        .copied()
+                        .filter(|char| !char.is_ascii())
+                        .collect();
+
+                    if !unexpected.is_empty() {
+                        warnings.push(format!(
+                            "non-ASCII characters {}, the parser expects ASCII only",
+                            format_chars(&unexpected)
+                        ));
+                    }
+                }
+                InputAssumption::Rectangular => {
+                    if self.row_lengths.len() > 1 {
+                        warnings.push(format!(
+                            "rows have {} different lengths, the parser expects all of them to be as long as the first one ({})",
+                            self.row_lengths.len(),
+                            self.first_row_length.unwrap_or(0)
+                        ));
+                    }
+                }
+                InputAssumption::Chars(allowed) => {
+                    let unexpected: Vec<char> = self
+                        .chars
+                        .keys()
+                        .copied()
+                        .filter(|char| !allowed.contains(*char))
+                        .collect();
+
+                    if !unexpected.is_empty() {
+                        warnings.push(format!(
+                            "unexpected characters {}, the parser only expects {}",
+                            format_chars(&unexpected),
+                            format_chars(&allowed.chars().collect::<Vec<char>>())
+                        ));
+                    }
+                }
+                InputAssumption::Lines(lines) => {
+                    if self.lines != lines {
+                        warnings.push(format!(
+                            "{} lines, the parser expects {}",
+                            self.lines, lines
+                        ));
+                    }
+                }
+                InputAssumption::Sections(sections) => {
+                    if self.sections.len() != sections {
+                        warnings.push(format!(
+                            "{} sections separated by blank lines, the parser expects {}",
+                            self.sections.len(),
+                            sections
+                        ));
+                    }
+                }
+            }
+        }
+
+        warnings
+    }
+
+    pub fn write_report(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "Lines: {}{}",
+            self.lines,
+            if self.ends_with_newline {
+                ""
+            } else {
+                ", no newline at the end"
+            }
+        )?;
+
+        let line_endings = match self.crlf_lines {
+            0 => String::from("LF"),
+            crlf if crlf == self.lines => String::from("CRLF"),
+            crlf => format!("mixed, {} CRLF", crlf),
+        };
+
+        writeln!(out, "Line endings: {}", line_endings)?;
+
+        let row_lengths: Vec<String> = self
+            .row_lengths
+            .iter()
+            .map(|(length, rows)| match rows {
+                1 => format!("{} (1 row)", length),
+                rows => format!("{} ({} rows)", length, rows),
+            })
+            .collect();
+
+        writeln!(
+            out,
+            "Row lengths: {}{}",
+            row_lengths.join(", "),
+            match self.row_lengths.len() {
+                0 | 1 => "",
+                _ => ", not rectangular",
+            }
+        )?;
+
+        writeln!(
+            out,
+            "Trailing whitespace: {}",
+            format_line_numbers(&self.trailing_whitespace)
+        )?;
+
+        let sections: Vec<String> = self
+            .sections
+            .iter()
+            .map(|(start, end)| format!("{}-{}", start, end))
+            .collect();
+
+        writeln!(
+            out,
+            "Sections: {} (lines {})",
+            self.sections.len(),
+            sections.join(", ")
+        )?;
+
+        let chars: Vec<String> = self
+            .chars
+            .iter()
+            .map(|(char, count)| format!("{:?} {}", char, count))
+            .collect();
+
+        writeln!(out, "Characters: {}", chars.join(", "))
+    }
+}
+
+fn format_chars(chars: &[char]) -> String {
+    chars
+        .iter()
+        .map(|char| format!("{:?}", char))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+// only the first few, a broken input can have a lot of them
+fn format_line_numbers(lines: &[usize]) -> String {
+    const SHOWN: usize = 10;
+
+    if lines.is_empty() {
+        return String::from("none");
+    }
+
+    let shown: Vec<String> = lines.iter().take(SHOWN).map(ToString::to_string).collect();
+
+    match lines.len() {
+        len if len > SHOWN => format!("lines {} and {} more", shown.join(", "), len - SHOWN),
+        _ => format!("lines {}", shown.join(", ")),
+    }
+}
+
+#[test]
+fn test_input_stats() {
+    let stats = InputStats::new("ab \r\nabc\n\nxé\n");
+
+    assert!(stats.lines == 4);
+    assert!(stats.ends_with_newline);
+    assert!(stats.crlf_lines == 1);
+    assert!(stats.row_lengths == BTreeMap::from([(2, 1), (3, 2)]));
+    assert!(stats.first_row_length == Some(3));
+    assert!(stats.chars[&'a'] == 2);
+    assert!(stats.trailing_whitespace == [1]);
+    assert!(stats.sections == [(1, 2), (4, 4)]);
+}
+
+#[test]
+fn test_check_assumptions() {
+    let stats = InputStats::new("#.^\n#.\n");
+
+    let warnings = stats.check(&[
+        InputAssumption::Ascii,
+        InputAssumption::Rectangular,
+        InputAssumption::Chars(".#"),
+        InputAssumption::Lines(2),
+        InputAssumption::Sections(2),
+    ]);
+
+    assert!(warnings.len() == 3);
+    assert!(warnings[0].starts_with("rows have 2 different lengths"));
+    assert!(warnings[1] == "unexpected characters '^', the parser only expects '.' '#'");
+    assert!(warnings[2] == "1 sections separated by blank lines, the parser expects 2");
+}
@@ -1,11 +1,190 @@
-use std::{fmt::Display, marker::PhantomData};
+use std::{fmt::Display, marker::PhantomData, process::ExitCode, time::Instant};
+
+mod alloc;
+mod answer_entry;
+mod answers;
+pub mod cli;
+mod context;
+mod direction;
+mod export;
+mod grid;
+pub mod history;
+pub mod inspect;
+mod options;
+pub mod output;
+mod params;
+mod parse;
+mod pool;
+mod position;
+mod records;
+pub mod registry;
+mod render;
+pub mod scaffold;
+pub mod site;
+pub mod submit;
+#[cfg(test)]
+mod testing;
+mod timing;
+mod watch;
+
+pub use alloc::{format_bytes, measure_allocations, AllocStats, CountingAllocator};
+pub use answers::{Answers, Status, ANSWERS_FILE_NAME};
+pub use context::{Context, Observer, StderrObserver, TraceEvent, TraceFilter, TraceLevel, Tracer};
+pub use direction::Direction;
+pub use export::{FrameWriter, ImageFormat};
+pub use grid::Grid;
+pub use inspect::InputAssumption;
+pub use options::{Part, PartSelection, RunOptions};
+pub use output::{OutputFormat, Reporter};
+pub use params::{ParamArg, ParamSpec, ParamType, ParamValue, Params};
+pub use parse::{Location, ParseError, Source, STDIN_INPUT};
+pub use pool::run_ordered;
+pub use position::{Offset, Position};
+pub use registry::{Day, DayNumber, Registry};
+pub use render::{ansi_supported, Canvas, Cell, Color};
+pub use timing::{format_duration, Timings};
 
 pub trait InputParser<TProblem> {
-    fn parse(&self, filename: &str) -> TProblem;
+    fn parse(&self, source: &Source) -> Result<TProblem, ParseError>;
+
+    /// What the parser expects of an input without checking it, see `aoc inspect`.
+    fn assumptions(&self) -> Vec<InputAssumption> {
+        Vec::new()
+    }
 }
 
+/// Solves both parts of a day, sharing a single parsed input between them.
 pub trait ProblemSolver<TProblem, TResult: Display> {
-    fn solve(&self, input: TProblem) -> TResult;
+    fn solve_part_one(&self, input: &TProblem, context: &Context) -> TResult;
+
+    fn solve_part_two(&self, input: &TProblem, context: &Context) -> TResult;
+
+    /// The parameters the solver reads from `context.params()`, set with `--param NAME=VALUE`.
+    fn params(&self) -> Vec<ParamSpec> {
+        Vec::new()
+    }
+
+    /// A picture of the input with what `context.part()` is looking for, shown by `aoc show`.
+    fn render(&self, _input: &TProblem, _context: &Context) -> Option<Canvas> {
+        None
+    }
+
+    /// Passes every step towards the answer of `context.part()` to `frame` as it is drawn,
+    /// and returns the final picture, see `aoc export --frames`.
+    fn animate(
+        &self,
+        _input: &TProblem,
+        _context: &Context,
+        _frame: &mut dyn FnMut(&Canvas),
+    ) -> Option<Canvas> {
+        None
+    }
+
+    fn solve(&self, input: &TProblem, context: &Context) -> TResult {
+        match context.part() {
+            Part::One => self.solve_part_one(input, context),
+            Part::Two => self.solve_part_two(input, context),
+        }
+    }
+}
+
+pub struct PartResult {
+    pub file: String,
+    pub part: Part,
+    pub answer: String,
+    pub expected: Option<String>,
+    pub status: Status,
+    // the parse timings are shared by all parts of the same file
+    pub parse_time: Timings,
+    pub solve_time: Timings,
+    // only measured with the `alloc-stats` feature, of the last repetition
+    pub parse_alloc: Option<AllocStats>,
+    pub solve_alloc: Option<AllocStats>,
+    pub input_hash: u64,
+}
+
+#[derive(Default)]
+pub struct RunReport {
+    pub results: Vec<PartResult>,
+    pub errors: Vec<ParseError>,
+}
+
+impl RunReport {
+    pub fn count(&self, status: Status) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.status == status)
+            .count()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty() && self.count(Status::Fail) == 0 && self.count(Status::Timeout) == 0
+    }
+
+    pub fn extend(&mut self, other: RunReport) {
+        self.results.extend(other.results);
+        self.errors.extend(other.errors);
+    }
+
+    /// Panics with every error and wrong answer, used by the generated answer tests.
+    pub fn assert_success(&self) {
+        let mut problems: Vec<String> = self.errors.iter().map(ToString::to_string).collect();
+
+        for result in self
+            .results
+            .iter()
+            .filter(|result| result.status == Status::Fail)
+        {
+            problems.push(format!(
+                "{} part {}: {}, expected {}",
+                result.file,
+                result.part,
+                result.answer,
+                result.expected.as_deref().unwrap_or("nothing")
+            ));
+        }
+
+        for result in self
+            .results
+            .iter()
+            .filter(|result| result.status == Status::Timeout)
+        {
+            problems.push(format!("{} part {}: timed out", result.file, result.part));
+        }
+
+        if !problems.is_empty() {
+            panic!("{}", problems.join("\n"));
+        }
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        if self.is_success() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Adds a `#[test]` for every entry of the day's `answers.txt`, generated by the build script.
+/// Use it once in each day module: `answer_tests!("05", Parser, Solver);`.
+#[macro_export]
+macro_rules! answer_tests {
+    ($day:literal, $parser:expr, $solver:expr) => {
+        #[cfg(test)]
+        fn run_answer_test(input_file: &str, part: &str) {
+            let options = $crate::RunOptions {
+                parts: part.parse().unwrap(),
+                ..Default::default()
+            };
+
+            $crate::Runner::new($day.parse().unwrap(), $parser, $solver)
+                .run(&options, &[input_file])
+                .assert_success();
+        }
+
+        include!(concat!(env!("OUT_DIR"), "/answer_tests_", $day, ".rs"));
+    };
 }
 
 pub struct Runner<TProblem, TResult, TInputParser, TProblemSolver>
@@ -14,11 +193,12 @@ where
     TInputParser: InputParser<TProblem>,
     TProblemSolver: ProblemSolver<TProblem, TResult>,
 {
+    day: DayNumber,
     parser: TInputParser,
     solver: TProblemSolver,
 
-    phantom_problem: PhantomData<TProblem>,
-    phantom_result: PhantomData<TResult>,
+    // the problem and result are never stored, so they do not affect `Send` and `Sync`
+    phantom: PhantomData<fn() -> (TProblem, TResult)>,
 }
 
 impl<TProblem, TResult, TInputParser, TProblemSolver>
@@ -28,22 +208,266 @@ where
     TInputParser: InputParser<TProblem>,
     TProblemSolver: ProblemSolver<TProblem, TResult>,
 {
-    pub fn new(parser: TInputParser, solver: TProblemSolver) -> Self {
+    pub fn new(day: DayNumber, parser: TInputParser, solver: TProblemSolver) -> Self {
         Runner {
+            day,
             parser,
             solver,
 
-            phantom_problem: PhantomData,
-            phantom_result: PhantomData,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Runs all input files, on up to `options.jobs` threads.
+    /// Nothing is printed, the report lists the results in the order of `input_files`.
+    pub fn run(&self, options: &RunOptions, input_files: &[&str]) -> RunReport
+    where
+        Self: Sync,
+    {
+        let mut report = RunReport::default();
+
+        let params = match Params::resolve(&self.solver.params(), &options.params) {
+            Ok(params) => params,
+            Err(error) => {
+                report.errors.push(error);
+                return report;
+            }
+        };
+
+        pool::run_ordered(
+            options.jobs,
+            input_files,
+            |filename| self.run_input(options, &params, filename),
+            |_, input_report| report.extend(input_report),
+        );
+
+        report
+    }
+
+    fn run_input(&self, options: &RunOptions, params: &Params, filename: &str) -> RunReport {
+        let mut report = RunReport::default();
+
+        // the known answers are those of the default parameters
+        let answers = if options.params.is_empty() {
+            Answers::for_input(filename).unwrap_or_else(|error| {
+                report.errors.push(error);
+
+                Answers::default()
+            })
+        } else {
+            Answers::default()
+        };
+
+        // the input is only loaded once so that stdin can be parsed repeatedly
+        // and so that reading the file does not count towards the parse time
+        let mut parse_alloc = None;
+        let (parsed, parse_time, input_hash) = match Source::load(filename) {
+            Err(error) => (Err(error), Timings::default(), 0),
+            Ok(source) => {
+                let (parsed, parse_time) = Timings::measure(options.repeat, || {
+                    let (parsed, stats) = measure_allocations(|| self.parser.parse(&source));
+                    parse_alloc = stats;
+
+                    parsed
+                });
+
+                (parsed, parse_time, history::input_hash(source.text()))
+            }
+        };
+
+        match parsed {
+            // a broken input should not prevent us from solving the remaining ones
+            Err(error) => report.errors.push(error),
+            Ok(input) => {
+                for part in options.parts.parts() {
+                    let mut solve_alloc = None;
+                    let mut timed_out = false;
+                    let (answer, solve_time) = Timings::measure(options.repeat, || {
+                        // every repetition gets the full budget
+                        let context = Context::new(options.trace.as_ref(), params, filename, *part)
+                            .with_deadline(options.timeout.map(|timeout| Instant::now() + timeout));
+                        let (answer, stats) =
+                            measure_allocations(|| self.solver.solve(&input, &context));
+                        solve_alloc = stats;
+                        // also catches solvers that never poll, they just cannot be stopped early
+                        timed_out |= context.is_cancelled();
+
+                        answer
+                    });
+                    let expected = answers.expected(filename, *part).map(String::from);
+                    let (answer, status) = if timed_out {
+                        (String::new(), Status::Timeout)
+                    } else {
+                        let answer = answer.to_string();
+                        let status = answers.check(filename, *part, &answer);
+
+                        (answer, status)
+                    };
+
+                    report.results.push(PartResult {
+                        file: filename.to_string(),
+                        part: *part,
+                        answer,
+                        expected,
+                        status,
+                        parse_time: parse_time.clone(),
+                        solve_time,
+                        parse_alloc,
+                        solve_alloc,
+                        input_hash,
+                    });
+                }
+            }
+        }
+
+        report
+    }
+}
+
+impl<TProblem, TResult, TInputParser, TProblemSolver> Day
+    for Runner<TProblem, TResult, TInputParser, TProblemSolver>
+where
+    TResult: Display,
+    TInputParser: InputParser<TProblem> + Send + Sync,
+    TProblemSolver: ProblemSolver<TProblem, TResult> + Send + Sync,
+{
+    fn number(&self) -> DayNumber {
+        self.day
+    }
+
+    fn params(&self) -> Vec<ParamSpec> {
+        self.solver.params()
+    }
+
+    fn input_assumptions(&self) -> Vec<InputAssumption> {
+        self.parser.assumptions()
+    }
+
+    fn run(&self, options: &RunOptions, input_files: &[&str]) -> RunReport {
+        Runner::run(self, options, input_files)
+    }
+
+    fn render(
+        &self,
+        part: Part,
+        params: &[ParamArg],
+        input_file: &str,
+    ) -> Result<Option<Canvas>, ParseError> {
+        let params = Params::resolve(&self.solver.params(), params)?;
+        let input = self.parser.parse(&Source::load(input_file)?)?;
+
+        Ok(self
+            .solver
+            .render(&input, &Context::new(None, &params, input_file, part)))
+    }
+
+    fn animate(
+        &self,
+        part: Part,
+        params: &[ParamArg],
+        input_file: &str,
+        frame: &mut dyn FnMut(&Canvas),
+    ) -> Result<Option<Canvas>, ParseError> {
+        let params = Params::resolve(&self.solver.params(), params)?;
+        let input = self.parser.parse(&Source::load(input_file)?)?;
+
+        Ok(self.solver.animate(
+            &input,
+            &Context::new(None, &params, input_file, part),
+            frame,
+        ))
+    }
+}
+
+#[test]
+fn test_run_reports_located_errors() {
+    struct Parser;
+
+    impl InputParser<u32> for Parser {
+        fn parse(&self, source: &Source) -> Result<u32, ParseError> {
+            source.parse(source.first_line()?)
+        }
+    }
+
+    struct Solver;
+
+    impl ProblemSolver<u32, u32> for Solver {
+        fn solve_part_one(&self, input: &u32, _context: &Context) -> u32 {
+            input * 2
+        }
+
+        fn solve_part_two(&self, input: &u32, _context: &Context) -> u32 {
+            input * 3
+        }
+    }
+
+    let dir = testing::temp_dir("runner");
+    let (bad, good) = (dir.join("bad.txt"), dir.join("good.txt"));
+
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&bad, "x1\n").unwrap();
+    std::fs::write(&good, "21\n").unwrap();
+
+    let (bad, good) = (bad.to_str().unwrap(), good.to_str().unwrap());
+    let report = Runner::new(1, Parser, Solver).run(&RunOptions::default(), &[bad, good]);
+
+    assert!(report.errors.len() == 1);
+    assert!(
+        report.errors[0].to_string()
+            == format!("{}:1:1: invalid digit found in string (found \"x1\")", bad)
+    );
+    assert!(report.results.len() == 2);
+    assert!(report.results.iter().all(|result| result.file == good));
+    assert!(report.results[0].answer == "42" && report.results[1].answer == "63");
+}
+
+#[test]
+fn test_run_times_out() {
+    struct Parser;
+
+    impl InputParser<u32> for Parser {
+        fn parse(&self, source: &Source) -> Result<u32, ParseError> {
+            source.parse(source.first_line()?)
         }
     }
 
-    pub fn run(&self, input_files: &Vec<&'_ str>) {
-        for filename in input_files.iter() {
-            let input = self.parser.parse(filename);
-            let result = self.solver.solve(input);
+    // spins on inputs other than 0 until the solve is cancelled
+    struct Solver;
+
+    impl ProblemSolver<u32, u32> for Solver {
+        fn solve_part_one(&self, input: &u32, context: &Context) -> u32 {
+            while *input != 0 && !context.is_cancelled() {
+                std::thread::yield_now();
+            }
 
-            println!("File {}: {}", filename, result)
+            *input
+        }
+
+        fn solve_part_two(&self, input: &u32, context: &Context) -> u32 {
+            self.solve_part_one(input, context)
         }
     }
+
+    let dir = testing::temp_dir("timeout");
+    let (slow, fast) = (dir.join("slow.txt"), dir.join("fast.txt"));
+
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&slow, "1\n").unwrap();
+    std::fs::write(&fast, "0\n").unwrap();
+    // the slow answer would pass if it was compared
+    std::fs::write(dir.join(ANSWERS_FILE_NAME), "slow.txt 1 1\nfast.txt 1 0\n").unwrap();
+
+    let options = RunOptions {
+        parts: PartSelection::One,
+        timeout: Some(std::time::Duration::from_millis(10)),
+        ..Default::default()
+    };
+    let report = Runner::new(1, Parser, Solver)
+        .run(&options, &[slow.to_str().unwrap(), fast.to_str().unwrap()]);
+
+    assert!(report.errors.is_empty());
+    assert!(report.results.len() == 2);
+    assert!(report.results[0].status == Status::Timeout);
+    assert!(report.results[0].answer.is_empty());
+    assert!(report.results[1].status == Status::Pass);
 }
@@ -0,0 +1,51 @@
+use std::process::ExitCode;
+
+use aoc_2024::{cli, Registry};
+
+#[path = "01/mod.rs"]
+mod day01;
+#[path = "02/mod.rs"]
+mod day02;
+#[path = "03/mod.rs"]
+mod day03;
+#[path = "04/mod.rs"]
+mod day04;
+#[path = "05/mod.rs"]
+mod day05;
+#[path = "06/mod.rs"]
+mod day06;
+#[path = "07/mod.rs"]
+mod day07;
+#[path = "08/mod.rs"]
+mod day08;
+#[path = "09/mod.rs"]
+mod day09;
+#[path = "10/mod.rs"]
+mod day10;
+#[path = "11/mod.rs"]
+mod day11;
+#[path = "12/mod.rs"]
+mod day12;
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    day01::register(&mut registry);
+    day02::register(&mut registry);
+    day03::register(&mut registry);
+    day04::register(&mut registry);
+    day05::register(&mut registry);
+    day06::register(&mut registry);
+    day07::register(&mut registry);
+    day08::register(&mut registry);
+    day09::register(&mut registry);
+    day10::register(&mut registry);
+    day11::register(&mut registry);
+    day12::register(&mut registry);
+
+    registry
+}
+
+fn main() -> ExitCode {
+    cli::main(&registry())
+}
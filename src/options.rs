@@ -0,0 +1,284 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use crate::{registry::DayNumber, OutputFormat, ParamArg, ParamSpec, Params, Tracer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part {:?}, expected 1 or 2", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartSelection {
+    One,
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    pub fn parts(&self) -> &'static [Part] {
+        match self {
+            PartSelection::One => &[Part::One],
+            PartSelection::Two => &[Part::Two],
+            PartSelection::Both => &[Part::One, Part::Two],
+        }
+    }
+}
+
+impl FromStr for PartSelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "1" => Ok(PartSelection::One),
+            "2" => Ok(PartSelection::Two),
+            "both" => Ok(PartSelection::Both),
+            _ => Err(format!("invalid part {:?}, expected 1, 2 or both", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub parts: PartSelection,
+    // how many times each input is parsed and solved to get stable timings
+    pub repeat: usize,
+    // how many input files (and days) are solved concurrently
+    pub jobs: usize,
+    pub format: OutputFormat,
+    // receives the trace events of the solvers, tracing is disabled when there is none
+    pub trace: Option<Tracer>,
+    pub params: Vec<ParamArg>,
+    // budget of every single solve, solvers that run out are reported as timed out
+    pub timeout: Option<Duration>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            parts: PartSelection::Both,
+            repeat: 1,
+            jobs: 1,
+            format: OutputFormat::Text,
+            trace: None,
+            params: Vec::new(),
+            timeout: None,
+        }
+    }
+}
+
+pub const RUN_OPTIONS_USAGE: &str =
+    "[--part 1|2|both] [--repeat N] [--jobs N] [--format text|json|csv|tap] [--param [DAY:]NAME=VALUE]... [--timeout SECONDS]";
+
+impl RunOptions {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = RunOptions::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if !options.parse_arg(&arg, &mut args)? {
+                return Err(format!("unknown argument {:?}", arg));
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// The options of a single day, only keeping the parameters meant for it.
+    /// A parameter without a day is meant for every day that declares it in `specs`.
+    pub fn for_day(&self, day: DayNumber, specs: &[ParamSpec]) -> RunOptions {
+        RunOptions {
+            params: self
+                .params
+                .iter()
+                .filter(|param| match param.day {
+                    Some(param_day) => param_day == day,
+                    None => specs.iter().any(|spec| spec.name == param.name),
+                })
+                .cloned()
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Checks that every parameter is declared by one of the selected `days`, as `for_day` drops
+    /// the others, and that its value is valid for each of them. This reports a mistake once
+    /// instead of once per input.
+    pub fn check_params(&self, days: &[(DayNumber, Vec<ParamSpec>)]) -> Result<(), String> {
+        for param in &self.params {
+            let known: Vec<&str> = days
+                .iter()
+                .filter(|(day, _)| param.day.is_none_or(|param_day| param_day == *day))
+                .flat_map(|(_, specs)| specs.iter().map(|spec| spec.name))
+                .collect();
+
+            if known.contains(&param.name.as_str()) {
+                continue;
+            }
+
+            return Err(if known.is_empty() {
+                format!(
+                    "{}: unknown parameter, not declared by any selected day",
+                    param
+                )
+            } else {
+                format!(
+                    "{}: unknown parameter, expected one of {}",
+                    param,
+                    known.join(", ")
+                )
+            });
+        }
+
+        for (day, specs) in days {
+            Params::resolve(specs, &self.for_day(*day, specs).params)
+                .map_err(|error| error.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    /// Applies a single option, taking its value from `args`.
+    /// Returns false if `arg` is not a run option so that commands can handle their own arguments.
+    pub fn parse_arg(
+        &mut self,
+        arg: &str,
+        args: &mut impl Iterator<Item = String>,
+    ) -> Result<bool, String> {
+        match arg {
+            "--part" => {
+                let value = args.next().ok_or("missing value for --part")?;
+
+                self.parts = value.parse()?;
+            }
+            "--repeat" => {
+                let value = args.next().ok_or("missing value for --repeat")?;
+
+                self.repeat = match value.parse() {
+                    Ok(repeat) if repeat > 0 => repeat,
+                    _ => return Err(format!("invalid repeat count {:?}", value)),
+                };
+            }
+            "--jobs" => {
+                let value = args.next().ok_or("missing value for --jobs")?;
+
+                self.jobs = match value.parse() {
+                    Ok(jobs) if jobs > 0 => jobs,
+                    _ => return Err(format!("invalid job count {:?}", value)),
+                };
+            }
+            "--format" => {
+                let value = args.next().ok_or("missing value for --format")?;
+
+                self.format = value.parse()?;
+            }
+            "--param" => {
+                let value = args.next().ok_or("missing value for --param")?;
+
+                self.params.push(value.parse()?);
+            }
+            "--timeout" => {
+                let value = args.next().ok_or("missing value for --timeout")?;
+
+                self.timeout = match value.parse().map(Duration::try_from_secs_f64) {
+                    Ok(Ok(timeout)) if !timeout.is_zero() => Some(timeout),
+                    _ => return Err(format!("invalid timeout {:?}", value)),
+                };
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+}
+
+#[test]
+fn test_parse_part() {
+    let args = ["--part", "2"].map(String::from);
+
+    assert!(RunOptions::parse(args).unwrap().parts == PartSelection::Two);
+    assert!(RunOptions::parse([]).unwrap().parts == PartSelection::Both);
+    assert!(RunOptions::parse(["--part".to_string(), "3".to_string()]).is_err());
+}
+
+#[test]
+fn test_parse_repeat() {
+    let args = ["--repeat", "5"].map(String::from);
+
+    assert!(RunOptions::parse(args).unwrap().repeat == 5);
+    assert!(RunOptions::parse(["--repeat", "0"].map(String::from)).is_err());
+}
+
+#[test]
+fn test_parse_format() {
+    let args = ["--format", "tap"].map(String::from);
+
+    assert!(RunOptions::parse(args).unwrap().format == OutputFormat::Tap);
+    assert!(RunOptions::parse(["--format", "xml"].map(String::from)).is_err());
+}
+
+#[test]
+fn test_parse_timeout() {
+    let args = ["--timeout", "1.5"].map(String::from);
+
+    assert!(RunOptions::parse(args).unwrap().timeout == Some(Duration::from_millis(1500)));
+    assert!(RunOptions::parse([]).unwrap().timeout.is_none());
+    assert!(RunOptions::parse(["--timeout", "0"].map(String::from)).is_err());
+    assert!(RunOptions::parse(["--timeout", "-1"].map(String::from)).is_err());
+}
+
+#[test]
+fn test_params_for_day() {
+    let options =
+        RunOptions::parse(["--param", "blinks=6", "--param", "4:word=MAS"].map(String::from))
+            .unwrap();
+
+    let word = [ParamSpec::text("word", None)];
+    let blinks = [ParamSpec::unsigned("blinks", None)];
+
+    assert!(options.for_day(4, &word).params.len() == 1);
+    assert!(options.for_day(4, &blinks).params.len() == 2);
+    assert!(options.for_day(11, &blinks).params.len() == 1);
+    assert!(options.for_day(2, &word).params.is_empty());
+
+    assert!(options
+        .check_params(&[(4, word.to_vec()), (11, blinks.to_vec())])
+        .is_ok());
+    assert!(
+        options.check_params(&[(4, word.to_vec())]).unwrap_err()
+            == "--param blinks=6: unknown parameter, expected one of word"
+    );
+    assert!(
+        options.check_params(&[(11, blinks.to_vec())]).unwrap_err()
+            == "--param 4:word=MAS: unknown parameter, not declared by any selected day"
+    );
+
+    let too_large = RunOptions::parse(["--param", "blinks=256"].map(String::from)).unwrap();
+    let fitting = vec![ParamSpec::unsigned("blinks", None).fitting::<u8>()];
+
+    assert!(
+        too_large.check_params(&[(11, fitting)]).unwrap_err()
+            == "--param blinks=256: 256 is too large"
+    );
+}
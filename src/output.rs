@@ -0,0 +1,598 @@
+use std::{
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{
+    alloc::format_bytes, registry::DayNumber, timing::format_duration, AllocStats, ParseError,
+    PartResult, RunReport, Status, Timings,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+    Tap,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            "tap" => Ok(OutputFormat::Tap),
+            _ => Err(format!(
+                "invalid format {:?}, expected text, json, csv or tap",
+                value
+            )),
+        }
+    }
+}
+
+/// Writes the reports of a run as they come in, `finish` is called once everything has been reported.
+pub trait Reporter {
+    fn report(&mut self, day: DayNumber, report: &RunReport) -> io::Result<()>;
+
+    fn finish(&mut self, total: &RunReport) -> io::Result<()>;
+}
+
+pub fn new_reporter<'a>(format: OutputFormat, out: impl Write + 'a) -> Box<dyn Reporter + 'a> {
+    match format {
+        OutputFormat::Text => Box::new(TextReporter::new(out)),
+        OutputFormat::Json => Box::new(JsonReporter::new(out)),
+        OutputFormat::Csv => Box::new(CsvReporter::new(out)),
+        OutputFormat::Tap => Box::new(TapReporter::new(out)),
+    }
+}
+
+fn nanos(duration: Duration) -> u128 {
+    duration.as_nanos()
+}
+
+pub struct TextReporter<W: Write> {
+    out: W,
+    last_day: Option<DayNumber>,
+}
+
+impl<W: Write> TextReporter<W> {
+    pub fn new(out: W) -> Self {
+        TextReporter {
+            out,
+            last_day: None,
+        }
+    }
+
+    fn write_timing_table(&mut self, total: &RunReport) -> io::Result<()> {
+        let format_timings = |timings: &Timings| {
+            format!(
+                "{} / {} / {}",
+                format_duration(timings.min()),
+                format_duration(timings.median()),
+                format_duration(timings.max())
+            )
+        };
+
+        let rows: Vec<[String; 4]> = total
+            .results
+            .iter()
+            .map(|result| {
+                [
+                    result.file.clone(),
+                    result.part.to_string(),
+                    format_timings(&result.parse_time),
+                    format_timings(&result.solve_time),
+                ]
+            })
+            .collect();
+
+        let header = [
+            "File",
+            "Part",
+            "Parse min / median / max",
+            "Solve min / median / max",
+        ];
+
+        self.write_table(header, &rows)
+    }
+
+    // only written when the allocations were measured, see the `alloc-stats` feature
+    fn write_alloc_table(&mut self, total: &RunReport) -> io::Result<()> {
+        let format_stats = |stats: Option<AllocStats>| {
+            stats.map_or(String::from("-"), |stats| {
+                format!(
+                    "{} / {} / {}",
+                    stats.allocations,
+                    format_bytes(stats.bytes),
+                    format_bytes(stats.peak)
+                )
+            })
+        };
+
+        let rows: Vec<[String; 4]> = total
+            .results
+            .iter()
+            .filter(|result| result.parse_alloc.is_some() || result.solve_alloc.is_some())
+            .map(|result| {
+                [
+                    result.file.clone(),
+                    result.part.to_string(),
+                    format_stats(result.parse_alloc),
+                    format_stats(result.solve_alloc),
+                ]
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let header = [
+            "File",
+            "Part",
+            "Parse allocations / bytes / peak",
+            "Solve allocations / bytes / peak",
+        ];
+
+        writeln!(self.out)?;
+        self.write_table(header, &rows)
+    }
+
+    fn write_table(&mut self, header: [&str; 4], rows: &[[String; 4]]) -> io::Result<()> {
+        let header = header.map(String::from);
+
+        let widths: Vec<usize> = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .chain([&header])
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        for row in [&header].into_iter().chain(rows.iter()) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+
+            writeln!(self.out, "{}", cells.join("  ").trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Reporter for TextReporter<W> {
+    fn report(&mut self, day: DayNumber, report: &RunReport) -> io::Result<()> {
+        if self.last_day != Some(day) {
+            writeln!(self.out, "Day {:02}", day)?;
+            self.last_day = Some(day);
+        }
+
+        for error in report.errors.iter() {
+            eprintln!("Error: {}", error);
+        }
+
+        for result in report.results.iter() {
+            match (&result.status, &result.expected) {
+                (Status::Timeout, _) => writeln!(
+                    self.out,
+                    "File {}, part {}: {}",
+                    result.file, result.part, result.status
+                )?,
+                (Status::Fail, Some(expected)) => writeln!(
+                    self.out,
+                    "File {}, part {}: {} ({}, expected {})",
+                    result.file, result.part, result.answer, result.status, expected
+                )?,
+                _ => writeln!(
+                    self.out,
+                    "File {}, part {}: {} ({})",
+                    result.file, result.part, result.answer, result.status
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, total: &RunReport) -> io::Result<()> {
+        writeln!(self.out)?;
+        self.write_timing_table(total)?;
+        self.write_alloc_table(total)?;
+        writeln!(self.out)?;
+
+        writeln!(
+            self.out,
+            "{} passed, {} failed, {} unknown, {} timed out, {} errors",
+            total.count(Status::Pass),
+            total.count(Status::Fail),
+            total.count(Status::Unknown),
+            total.count(Status::Timeout),
+            total.errors.len()
+        )
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::from('"');
+
+    for char in value.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            char if char.is_control() => escaped.push_str(&format!("\\u{:04x}", char as u32)),
+            char => escaped.push(char),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn json_alloc(stats: Option<AllocStats>) -> String {
+    stats.map_or(String::from("null"), |stats| {
+        format!(
+            "{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}",
+            stats.allocations, stats.bytes, stats.peak
+        )
+    })
+}
+
+fn json_timings(timings: &Timings) -> String {
+    format!(
+        "{{\"min\":{},\"median\":{},\"max\":{}}}",
+        nanos(timings.min()),
+        nanos(timings.median()),
+        nanos(timings.max())
+    )
+}
+
+/// Writes a single JSON array with one object per part result or parse error.
+pub struct JsonReporter<W: Write> {
+    out: W,
+    rows: usize,
+}
+
+impl<W: Write> JsonReporter<W> {
+    pub fn new(out: W) -> Self {
+        JsonReporter { out, rows: 0 }
+    }
+
+    fn write_row(&mut self, row: String) -> io::Result<()> {
+        let separator = if self.rows == 0 { "[\n  " } else { ",\n  " };
+
+        self.rows += 1;
+
+        write!(self.out, "{}{}", separator, row)
+    }
+}
+
+impl<W: Write> Reporter for JsonReporter<W> {
+    fn report(&mut self, day: DayNumber, report: &RunReport) -> io::Result<()> {
+        for error in report.errors.iter() {
+            self.write_row(format!(
+                "{{\"day\":{},\"part\":null,\"file\":{},\"answer\":null,\"expected\":null,\"status\":\"error\",\"message\":{}}}",
+                day,
+                json_string(&error.file),
+                json_string(&error.to_string())
+            ))?;
+        }
+
+        for result in report.results.iter() {
+            self.write_row(format!(
+                "{{\"day\":{},\"part\":{},\"file\":{},\"answer\":{},\"expected\":{},\"status\":\"{}\",\"parse_time_ns\":{},\"solve_time_ns\":{},\"parse_alloc\":{},\"solve_alloc\":{}}}",
+                day,
+                result.part,
+                json_string(&result.file),
+                json_string(&result.answer),
+                result.expected.as_deref().map_or(String::from("null"), json_string),
+                result.status.name(),
+                json_timings(&result.parse_time),
+                json_timings(&result.solve_time),
+                json_alloc(result.parse_alloc),
+                json_alloc(result.solve_alloc)
+            ))?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, _total: &RunReport) -> io::Result<()> {
+        if self.rows == 0 {
+            writeln!(self.out, "[]")
+        } else {
+            writeln!(self.out, "\n]")
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Splits a single line written by `CsvReporter` back into its fields.
+pub fn parse_csv_record(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        let field = fields.last_mut().unwrap();
+
+        match char {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            char => field.push(char),
+        }
+    }
+
+    fields
+}
+
+// empty fields when the allocations were not measured
+fn csv_alloc(stats: Option<AllocStats>) -> String {
+    stats.map_or(String::from(",,"), |stats| {
+        format!("{},{},{}", stats.allocations, stats.bytes, stats.peak)
+    })
+}
+
+pub const CSV_HEADER: &str = "day,part,file,answer,expected,status,parse_min_ns,parse_median_ns,parse_max_ns,solve_min_ns,solve_median_ns,solve_max_ns,parse_allocations,parse_bytes,parse_peak_bytes,solve_allocations,solve_bytes,solve_peak_bytes,message";
+
+pub struct CsvReporter<W: Write> {
+    out: W,
+    header_written: bool,
+}
+
+impl<W: Write> CsvReporter<W> {
+    pub fn new(out: W) -> Self {
+        CsvReporter {
+            out,
+            header_written: false,
+        }
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if !self.header_written {
+            writeln!(self.out, "{}", CSV_HEADER)?;
+            self.header_written = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Reporter for CsvReporter<W> {
+    fn report(&mut self, day: DayNumber, report: &RunReport) -> io::Result<()> {
+        self.write_header()?;
+
+        for error in report.errors.iter() {
+            writeln!(
+                self.out,
+                "{},,{},,,error,,,,,,,,,,,,,{}",
+                day,
+                csv_field(&error.file),
+                csv_field(&error.to_string())
+            )?;
+        }
+
+        for result in report.results.iter() {
+            writeln!(
+                self.out,
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},",
+                day,
+                result.part,
+                csv_field(&result.file),
+                csv_field(&result.answer),
+                csv_field(result.expected.as_deref().unwrap_or("")),
+                result.status.name(),
+                nanos(result.parse_time.min()),
+                nanos(result.parse_time.median()),
+                nanos(result.parse_time.max()),
+                nanos(result.solve_time.min()),
+                nanos(result.solve_time.median()),
+                nanos(result.solve_time.max()),
+                csv_alloc(result.parse_alloc),
+                csv_alloc(result.solve_alloc)
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, _total: &RunReport) -> io::Result<()> {
+        self.write_header()
+    }
+}
+
+/// Writes TAP version 13, results without a known answer are reported as skipped.
+pub struct TapReporter<W: Write> {
+    out: W,
+    tests: usize,
+}
+
+impl<W: Write> TapReporter<W> {
+    pub fn new(out: W) -> Self {
+        TapReporter { out, tests: 0 }
+    }
+
+    fn start_test(&mut self) -> io::Result<usize> {
+        if self.tests == 0 {
+            writeln!(self.out, "TAP version 13")?;
+        }
+
+        self.tests += 1;
+
+        Ok(self.tests)
+    }
+
+    fn write_error(&mut self, day: DayNumber, error: &ParseError) -> io::Result<()> {
+        let test = self.start_test()?;
+
+        writeln!(self.out, "not ok {} - day {:02} {}", test, day, error.file)?;
+        writeln!(self.out, "  ---")?;
+        writeln!(self.out, "  message: {}", json_string(&error.to_string()))?;
+        writeln!(self.out, "  ...")
+    }
+
+    fn write_result(&mut self, day: DayNumber, result: &PartResult) -> io::Result<()> {
+        let test = self.start_test()?;
+
+        let description = format!("day {:02} {} part {}", day, result.file, result.part);
+
+        match result.status {
+            Status::Pass => writeln!(self.out, "ok {} - {}", test, description)?,
+            Status::Fail => writeln!(self.out, "not ok {} - {}", test, description)?,
+            Status::Timeout => writeln!(self.out, "not ok {} - {} # timed out", test, description)?,
+            Status::Unknown => writeln!(
+                self.out,
+                "ok {} - {} # SKIP no expected answer",
+                test, description
+            )?,
+        }
+
+        writeln!(self.out, "  ---")?;
+        writeln!(self.out, "  answer: {}", json_string(&result.answer))?;
+
+        if let Some(expected) = &result.expected {
+            writeln!(self.out, "  expected: {}", json_string(expected))?;
+        }
+
+        writeln!(
+            self.out,
+            "  parse_median_ns: {}",
+            nanos(result.parse_time.median())
+        )?;
+        writeln!(
+            self.out,
+            "  solve_median_ns: {}",
+            nanos(result.solve_time.median())
+        )?;
+
+        if let Some(stats) = result.parse_alloc {
+            writeln!(self.out, "  parse_allocations: {}", stats.allocations)?;
+            writeln!(self.out, "  parse_peak_bytes: {}", stats.peak)?;
+        }
+
+        if let Some(stats) = result.solve_alloc {
+            writeln!(self.out, "  solve_allocations: {}", stats.allocations)?;
+            writeln!(self.out, "  solve_peak_bytes: {}", stats.peak)?;
+        }
+
+        writeln!(self.out, "  ...")
+    }
+}
+
+impl<W: Write> Reporter for TapReporter<W> {
+    fn report(&mut self, day: DayNumber, report: &RunReport) -> io::Result<()> {
+        for error in report.errors.iter() {
+            self.write_error(day, error)?;
+        }
+
+        for result in report.results.iter() {
+            self.write_result(day, result)?;
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, _total: &RunReport) -> io::Result<()> {
+        if self.tests == 0 {
+            writeln!(self.out, "TAP version 13")?;
+        }
+
+        writeln!(self.out, "1..{}", self.tests)
+    }
+}
+
+#[test]
+fn test_output_formats() {
+    let report = RunReport {
+        results: vec![PartResult {
+            file: String::from("src/01/input_1.txt"),
+            part: crate::Part::Two,
+            answer: String::from("31"),
+            expected: Some(String::from("30")),
+            status: Status::Fail,
+            parse_time: Timings::from_samples(vec![Duration::from_nanos(10)]),
+            solve_time: Timings::from_samples(vec![Duration::from_nanos(20)]),
+            parse_alloc: None,
+            solve_alloc: Some(AllocStats {
+                allocations: 2,
+                bytes: 48,
+                peak: 32,
+            }),
+            input_hash: 0,
+        }],
+        errors: vec![ParseError {
+            file: String::from("src/01/input_2.txt"),
+            location: None,
+            message: String::from("say \"hi\""),
+        }],
+    };
+
+    let write = |format| {
+        let mut out = Vec::new();
+
+        {
+            let mut reporter = new_reporter(format, &mut out);
+
+            reporter.report(1, &report).unwrap();
+            reporter.finish(&report).unwrap();
+        }
+
+        String::from_utf8(out).unwrap()
+    };
+
+    let output = write(OutputFormat::Json);
+
+    assert!(output.starts_with("[\n  {\"day\":1,\"part\":null,"));
+    assert!(output.contains("\"message\":\"src/01/input_2.txt: say \\\"hi\\\"\""));
+    assert!(output.contains("\"answer\":\"31\",\"expected\":\"30\",\"status\":\"fail\",\"parse_time_ns\":{\"min\":10,\"median\":10,\"max\":10}"));
+    assert!(output.contains(
+        "\"parse_alloc\":null,\"solve_alloc\":{\"allocations\":2,\"bytes\":48,\"peak_bytes\":32}"
+    ));
+    assert!(output.ends_with("}\n]\n"));
+
+    let output = write(OutputFormat::Csv);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert!(lines[0] == CSV_HEADER);
+    assert!(
+        lines[1]
+            == "1,,src/01/input_2.txt,,,error,,,,,,,,,,,,,\"src/01/input_2.txt: say \"\"hi\"\"\""
+    );
+    assert!(lines[2] == "1,2,src/01/input_1.txt,31,30,fail,10,10,10,20,20,20,,,,2,48,32,");
+
+    let output = write(OutputFormat::Tap);
+
+    assert!(output.starts_with("TAP version 13\nnot ok 1 - day 01 src/01/input_2.txt\n"));
+    assert!(output.contains("not ok 2 - day 01 src/01/input_1.txt part 2\n"));
+    assert!(output.ends_with("1..2\n"));
+}
+
+#[test]
+fn test_parse_csv_record() {
+    let fields = ["1", "", "a,b", "say \"hi\""];
+    let line: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+
+    assert!(parse_csv_record(&line.join(",")) == fields);
+}
@@ -0,0 +1,307 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::{registry::DayNumber, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamType {
+    Unsigned,
+    Text,
+}
+
+impl Display for ParamType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamType::Unsigned => write!(f, "unsigned integer"),
+            ParamType::Text => write!(f, "text"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamValue {
+    Unsigned(u64),
+    Text(String),
+}
+
+impl ParamValue {
+    fn parse(kind: ParamType, value: &str) -> Result<Self, String> {
+        match kind {
+            ParamType::Unsigned => value
+                .parse()
+                .map(ParamValue::Unsigned)
+                .map_err(|_| format!("expected an {}, found {:?}", kind, value)),
+            ParamType::Text => Ok(ParamValue::Text(value.to_string())),
+        }
+    }
+}
+
+// returns why a text value cannot be used
+type TextCheck = fn(&str) -> Result<(), &'static str>;
+
+/// A named parameter declared by a solver.
+/// Without a default the solver picks a value itself, e.g. one per part.
+#[derive(Debug, Clone)]
+pub struct ParamSpec {
+    pub name: &'static str,
+    pub kind: ParamType,
+    pub default: Option<ParamValue>,
+    // whether an unsigned value fits the type the solver reads it as, see `fitting`
+    pub fits: Option<fn(u64) -> bool>,
+    // the largest unsigned value the solver can handle, see `at_most`
+    pub max: Option<u64>,
+    // see `checked`
+    pub check: Option<TextCheck>,
+}
+
+impl ParamSpec {
+    pub fn unsigned(name: &'static str, default: Option<u64>) -> Self {
+        ParamSpec {
+            name,
+            kind: ParamType::Unsigned,
+            default: default.map(ParamValue::Unsigned),
+            fits: None,
+            max: None,
+            check: None,
+        }
+    }
+
+    pub fn text(name: &'static str, default: Option<&str>) -> Self {
+        ParamSpec {
+            name,
+            kind: ParamType::Text,
+            default: default.map(|default| ParamValue::Text(default.to_string())),
+            fits: None,
+            max: None,
+            check: None,
+        }
+    }
+
+    /// Rejects unsigned values that don't fit a `T`, so that the solver can read them with
+    /// `Params::unsigned_as` instead of truncating them.
+    pub fn fitting<T: TryFrom<u64>>(self) -> Self {
+        ParamSpec {
+            fits: Some(|value| T::try_from(value).is_ok()),
+            ..self
+        }
+    }
+
+    /// Rejects unsigned values above `max`, e.g. where larger ones make no sense or take too long.
+    pub fn at_most(self, max: u64) -> Self {
+        ParamSpec {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    /// Rejects text values for which `check` returns why they cannot be used.
+    pub fn checked(self, check: TextCheck) -> Self {
+        ParamSpec {
+            check: Some(check),
+            ..self
+        }
+    }
+
+    fn validate(&self, value: &ParamValue) -> Result<(), String> {
+        match value {
+            ParamValue::Unsigned(value) => {
+                if self.fits.is_some_and(|fits| !fits(*value)) {
+                    return Err(format!("{} is too large", value));
+                }
+
+                match self.max {
+                    Some(max) if *value > max => {
+                        Err(format!("{} is too large, expected at most {}", value, max))
+                    }
+                    _ => Ok(()),
+                }
+            }
+            ParamValue::Text(value) => match self.check {
+                Some(check) => check(value).map_err(String::from),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+/// A `--param [DAY:]NAME=VALUE` argument, the day limits it to a single day when running several.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamArg {
+    pub day: Option<DayNumber>,
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for ParamArg {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (name, param_value) = value
+            .split_once('=')
+            .ok_or_else(|| format!("invalid parameter {:?}, expected NAME=VALUE", value))?;
+
+        let (day, name) = match name.split_once(':') {
+            None => (None, name),
+            Some((day, name)) => match day.parse() {
+                Ok(day) if (1..=25).contains(&day) => (Some(day), name),
+                _ => return Err(format!("invalid day {:?}, expected 1 to 25", day)),
+            },
+        };
+
+        if name.is_empty() {
+            return Err(format!("invalid parameter {:?}, the name is empty", value));
+        }
+
+        Ok(ParamArg {
+            day,
+            name: name.to_string(),
+            value: param_value.to_string(),
+        })
+    }
+}
+
+impl Display for ParamArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "--param {}:{}={}", day, self.name, self.value),
+            None => write!(f, "--param {}={}", self.name, self.value),
+        }
+    }
+}
+
+/// The values of every parameter declared by a solver.
+#[derive(Debug, Default)]
+pub struct Params {
+    values: BTreeMap<&'static str, (ParamType, Option<ParamValue>)>,
+}
+
+impl Params {
+    pub const fn empty() -> Self {
+        Params {
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Applies `args` to the defaults of `specs`, unknown names and values of the wrong type are errors.
+    pub fn resolve(specs: &[ParamSpec], args: &[ParamArg]) -> Result<Self, ParseError> {
+        let mut values: BTreeMap<&'static str, (ParamType, Option<ParamValue>)> = specs
+            .iter()
+            .map(|spec| (spec.name, (spec.kind, spec.default.clone())))
+            .collect();
+
+        for arg in args {
+            let error = |message: String| ParseError {
+                file: arg.to_string(),
+                location: None,
+                message,
+            };
+
+            let Some(spec) = specs.iter().find(|spec| spec.name == arg.name) else {
+                let known: Vec<&str> = specs.iter().map(|spec| spec.name).collect();
+
+                return Err(error(if known.is_empty() {
+                    String::from("unknown parameter, the solver has none")
+                } else {
+                    format!("unknown parameter, expected one of {}", known.join(", "))
+                }));
+            };
+
+            let value = ParamValue::parse(spec.kind, &arg.value).map_err(error)?;
+
+            spec.validate(&value).map_err(error)?;
+
+            values.insert(spec.name, (spec.kind, Some(value)));
+        }
+
+        Ok(Params { values })
+    }
+
+    fn get(&self, name: &str, kind: ParamType) -> Option<&ParamValue> {
+        match self.values.get(name) {
+            None => panic!("parameter {:?} is not declared by the solver", name),
+            Some((declared, _)) if *declared != kind => {
+                panic!("parameter {:?} is a {}, not a {}", name, declared, kind)
+            }
+            Some((_, value)) => value.as_ref(),
+        }
+    }
+
+    pub fn unsigned(&self, name: &str) -> Option<u64> {
+        match self.get(name, ParamType::Unsigned)? {
+            ParamValue::Unsigned(value) => Some(*value),
+            ParamValue::Text(_) => unreachable!(),
+        }
+    }
+
+    /// Converts an unsigned parameter declared with `ParamSpec::fitting::<T>`.
+    pub fn unsigned_as<T: TryFrom<u64>>(&self, name: &str) -> Option<T> {
+        let value = self.unsigned(name)?;
+
+        match T::try_from(value) {
+            Ok(value) => Some(value),
+            Err(_) => panic!("parameter {:?} is not declared to fit the type", name),
+        }
+    }
+
+    pub fn text(&self, name: &str) -> Option<&str> {
+        match self.get(name, ParamType::Text)? {
+            ParamValue::Text(value) => Some(value),
+            ParamValue::Unsigned(_) => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn test_resolve_params() {
+    let specs = [
+        ParamSpec::unsigned("blinks", None),
+        ParamSpec::text("word", Some("XMAS")),
+    ];
+    let args: Vec<ParamArg> = ["blinks=6", "11:word=SAMX"]
+        .iter()
+        .map(|arg| arg.parse().unwrap())
+        .collect();
+
+    let params = Params::resolve(&specs, &args).unwrap();
+
+    assert!(params.unsigned("blinks") == Some(6));
+    assert!(params.text("word") == Some("SAMX"));
+    assert!(Params::resolve(&specs, &[])
+        .unwrap()
+        .unsigned("blinks")
+        .is_none());
+}
+
+#[test]
+fn test_invalid_params() {
+    let specs = [ParamSpec::unsigned("blinks", Some(25))];
+
+    let unknown = Params::resolve(&specs, &["blink=6".parse().unwrap()]).unwrap_err();
+    let invalid = Params::resolve(&specs, &["blinks=-1".parse().unwrap()]).unwrap_err();
+    let fitting = [ParamSpec::unsigned("blinks", None).fitting::<u8>()];
+    let too_large = Params::resolve(&fitting, &["blinks=256".parse().unwrap()]).unwrap_err();
+    let largest = Params::resolve(&fitting, &["blinks=255".parse().unwrap()]).unwrap();
+
+    assert!(unknown.to_string() == "--param blink=6: unknown parameter, expected one of blinks");
+    assert!(invalid.to_string() == "--param blinks=-1: expected an unsigned integer, found \"-1\"");
+    assert!(too_large.to_string() == "--param blinks=256: 256 is too large");
+    assert!(largest.unsigned_as::<u8>("blinks") == Some(255));
+
+    let bounded = [
+        ParamSpec::unsigned("blinks", None).at_most(75),
+        ParamSpec::text("word", None).checked(|word| {
+            if word.is_empty() {
+                return Err("the word is empty");
+            }
+
+            Ok(())
+        }),
+    ];
+    let above = Params::resolve(&bounded, &["blinks=76".parse().unwrap()]).unwrap_err();
+    let empty = Params::resolve(&bounded, &["word=".parse().unwrap()]).unwrap_err();
+
+    assert!(above.to_string() == "--param blinks=76: 76 is too large, expected at most 75");
+    assert!(empty.to_string() == "--param word=: the word is empty");
+    assert!(Params::resolve(&bounded, &["blinks=75".parse().unwrap()]).is_ok());
+    assert!("26:blinks=1".parse::<ParamArg>().is_err());
+    assert!("blinks".parse::<ParamArg>().is_err());
+}
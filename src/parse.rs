@@ -0,0 +1,200 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::read_to_string,
+    io::{stdin, BufRead},
+    str::FromStr,
+};
+
+/// Input file name meaning the input is read from stdin instead.
+pub const STDIN_INPUT: &str = "-";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: String,
+    // there is no location when the file could not be read at all
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            None => write!(f, "{}: {}", self.file, self.message),
+            Some(location) => write!(
+                f,
+                "{}:{}:{}: {} (found {:?})",
+                self.file, location.line, location.column, self.message, location.text
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The text of an input file together with its name, used to build located parse errors.
+pub struct Source {
+    name: String,
+    text: String,
+}
+
+impl Source {
+    pub fn new(name: &str, text: &str) -> Self {
+        Source {
+            name: name.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    pub fn from_reader(name: &str, mut reader: impl BufRead) -> Result<Self, ParseError> {
+        let mut text = String::new();
+
+        match reader.read_to_string(&mut text) {
+            Err(error) => Err(ParseError {
+                file: name.to_string(),
+                location: None,
+                message: format!("could not read input: {}", error),
+            }),
+            Ok(_) => Ok(Source {
+                name: name.to_string(),
+                text,
+            }),
+        }
+    }
+
+    /// Reads the file `filename`, or stdin if it is `-`.
+    pub fn load(filename: &str) -> Result<Self, ParseError> {
+        if filename == STDIN_INPUT {
+            return Source::from_reader("<stdin>", stdin().lock());
+        }
+
+        Source::read(filename)
+    }
+
+    pub fn read(filename: &str) -> Result<Self, ParseError> {
+        match read_to_string(filename) {
+            Err(error) => Err(ParseError {
+                file: filename.to_string(),
+                location: None,
+                message: format!("could not read file: {}", error),
+            }),
+            Ok(text) => Ok(Source {
+                name: filename.to_string(),
+                text,
+            }),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    fn locate(&self, at: &str) -> Option<Location> {
+        let start = self.text.as_ptr() as usize;
+        let at_start = at.as_ptr() as usize;
+
+        // the location can only be computed for slices borrowed from our own text
+        if at_start < start || at_start + at.len() > start + self.text.len() {
+            return None;
+        }
+
+        let preceding = &self.text[..at_start - start];
+        let line_start = preceding.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+        Some(Location {
+            line: preceding.matches('\n').count() + 1,
+            column: preceding[line_start..].chars().count() + 1,
+            text: at.to_string(),
+        })
+    }
+
+    /// Creates an error pointing at `at`, which has to be a slice of this source's text
+    /// (a line, a token, a capture...) for the line and column to be known.
+    pub fn error(&self, at: &str, message: impl Display) -> ParseError {
+        ParseError {
+            file: self.name.clone(),
+            location: self.locate(at),
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates an error pointing at the character starting at byte `idx` of `text`.
+    pub fn char_error(&self, text: &str, idx: usize, message: impl Display) -> ParseError {
+        let char_len = text[idx..].chars().next().map_or(0, char::len_utf8);
+
+        self.error(&text[idx..idx + char_len], message)
+    }
+
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        token.parse().map_err(|error| self.error(token, error))
+    }
+
+    pub fn split_once<'a>(
+        &self,
+        line: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        line.split_once(delimiter)
+            .ok_or_else(|| self.error(line, format!("expected {:?}", delimiter)))
+    }
+
+    /// The first line of the input, or an error if the input is empty.
+    pub fn first_line(&self) -> Result<&str, ParseError> {
+        self.lines()
+            .next()
+            .ok_or_else(|| self.error(&self.text, "input is empty"))
+    }
+}
+
+#[test]
+fn test_error_location() {
+    let source = Source::new("example.txt", "1 2\n3 x4\n");
+
+    let token = source.lines().nth(1).unwrap().split_once(' ').unwrap().1;
+
+    let error = source.parse::<u32>(token).unwrap_err();
+
+    assert!(
+        error.location
+            == Some(Location {
+                line: 2,
+                column: 3,
+                text: String::from("x4"),
+            })
+    );
+    assert!(error.to_string().starts_with("example.txt:2:3: "));
+}
+
+#[test]
+fn test_from_reader() {
+    let source = Source::from_reader("reader", "3   4\n4   3\n".as_bytes()).unwrap();
+
+    assert!(source.name() == "reader");
+    assert!(source.lines().count() == 2);
+}
+
+#[test]
+fn test_error_outside_of_source() {
+    let source = Source::new("example.txt", "1 2\n");
+
+    assert!(source.error("foreign", "oops").location.is_none());
+}
@@ -0,0 +1,92 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Runs `work` on every task using up to `jobs` scoped threads.
+/// The results are handed to `on_result` on the calling thread as soon as possible, but always in task order,
+/// so that whatever it prints does not depend on how the threads were scheduled.
+pub fn run_ordered<T, R>(
+    jobs: usize,
+    tasks: &[T],
+    work: impl Fn(&T) -> R + Sync,
+    mut on_result: impl FnMut(usize, R),
+) where
+    T: Sync,
+    R: Send,
+{
+    if jobs <= 1 || tasks.len() <= 1 {
+        for (idx, task) in tasks.iter().enumerate() {
+            on_result(idx, work(task));
+        }
+
+        return;
+    }
+
+    let next_task = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(tasks.len()) {
+            let sender = sender.clone();
+            let next_task = &next_task;
+            let work = &work;
+
+            scope.spawn(move || loop {
+                let idx = next_task.fetch_add(1, Ordering::Relaxed);
+
+                let Some(task) = tasks.get(idx) else {
+                    break;
+                };
+
+                if sender.send((idx, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        // the workers hold the remaining senders, so the loop below ends once all of them are done
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next_to_report = 0;
+
+        for (idx, result) in receiver {
+            finished.insert(idx, result);
+
+            while let Some(result) = finished.remove(&next_to_report) {
+                on_result(next_to_report, result);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+#[test]
+fn test_results_are_ordered() {
+    let tasks: Vec<u64> = (0..50).collect();
+    let mut results = Vec::new();
+
+    run_ordered(
+        4,
+        &tasks,
+        |task| {
+            // make later tasks finish first
+            thread::sleep(std::time::Duration::from_micros(50 - task));
+            task * 2
+        },
+        |idx, result| results.push((idx, result)),
+    );
+
+    assert!(
+        results
+            == tasks
+                .iter()
+                .map(|task| (*task as usize, task * 2))
+                .collect::<Vec<_>>()
+    );
+}
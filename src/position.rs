@@ -0,0 +1,100 @@
+use std::ops::{Add, Mul, Neg};
+
+/// A cell of a grid, `x` is the column and `y` the row counted from the top.
+/// Moves are checked, a position never wraps around to the other side of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Position {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Position { x, y }
+    }
+
+    /// Moves by `offset`, or returns `None` if either coordinate would become negative.
+    /// Use `Grid::step` to stay within a grid as well.
+    pub fn checked_add(self, offset: Offset) -> Option<Position> {
+        Some(Position {
+            x: self.x.checked_add_signed(offset.x)?,
+            y: self.y.checked_add_signed(offset.y)?,
+        })
+    }
+
+    pub fn offset_to(self, other: Position) -> Offset {
+        Offset {
+            x: other.x as isize - self.x as isize,
+            y: other.y as isize - self.y as isize,
+        }
+    }
+}
+
+/// The signed difference between two positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Offset {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Offset {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Offset { x, y }
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Offset {
+        Offset {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Offset {
+        Offset {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, factor: isize) -> Offset {
+        Offset {
+            x: self.x * factor,
+            y: self.y * factor,
+        }
+    }
+}
+
+#[test]
+fn test_checked_add() {
+    let position = Position::new(1, 2);
+
+    assert!(position.checked_add(Offset::new(-1, 3)) == Some(Position::new(0, 5)));
+    assert!(position.checked_add(Offset::new(-2, 0)).is_none());
+    assert!(position.checked_add(Offset::new(0, -1) * 3).is_none());
+    assert!(Position::new(usize::MAX, 0)
+        .checked_add(Offset::new(1, 0))
+        .is_none());
+}
+
+#[test]
+fn test_offset_to() {
+    let from = Position::new(4, 1);
+    let to = Position::new(2, 3);
+
+    assert!(from.offset_to(to) == Offset::new(-2, 2));
+    assert!(from.checked_add(from.offset_to(to)) == Some(to));
+    assert!(from.offset_to(to) + to.offset_to(from) == Offset::default());
+    assert!(-from.offset_to(to) == to.offset_to(from));
+}
@@ -0,0 +1,70 @@
+use std::{
+    fmt::Display,
+    fs::{create_dir_all, OpenOptions},
+    io::{self, Write},
+    path::Path,
+};
+
+use crate::{ParseError, Source};
+
+/// Parses a file with one record per line, like the benchmark history and the submission ledger.
+/// `#` comments and empty lines are ignored.
+pub fn parse_records<T>(
+    source: &Source,
+    parse: impl Fn(&Source, &str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    source
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| parse(source, line))
+        .collect()
+}
+
+/// Loads the records of a file, a missing file is the same as an empty one.
+pub fn load_records<T>(
+    file: &str,
+    parse: impl Fn(&Source, &str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    if !Path::new(file).exists() {
+        return Ok(Vec::new());
+    }
+
+    parse_records(&Source::read(file)?, parse)
+}
+
+/// Appends one line per record, a new file starts with `header` naming the fields.
+pub fn append_records(file: &str, header: &str, records: &[impl Display]) -> io::Result<()> {
+    let path = Path::new(file);
+
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir)?;
+    }
+
+    let is_new = !path.exists();
+    let mut out = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(out, "{}", header)?;
+    }
+
+    for record in records {
+        writeln!(out, "{}", record)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_append_records() {
+    let file = crate::testing::temp_dir("records").join("records.txt");
+    let file = file.to_str().unwrap();
+    let parse = |source: &Source, line: &str| source.parse::<u32>(line);
+
+    assert!(load_records(file, parse).unwrap().is_empty());
+
+    append_records(file, "# number", &[1, 2]).unwrap();
+    append_records(file, "# number", &[3]).unwrap();
+
+    assert!(load_records(file, parse).unwrap() == [1, 2, 3]);
+    assert!(std::fs::read_to_string(file).unwrap() == "# number\n1\n2\n3\n");
+}
@@ -0,0 +1,191 @@
+use std::{fs::read_dir, path::PathBuf};
+
+use crate::{
+    Canvas, InputAssumption, ParamArg, ParamSpec, ParseError, Part, RunOptions, RunReport,
+};
+
+pub type DayNumber = u8;
+
+/// A puzzle that can be run on any of its inputs.
+/// It is object safe, so that days with different input and answer types can be stored together.
+/// Every `Runner` of an `InputParser` and a `ProblemSolver` is a day.
+pub trait Day: Send + Sync {
+    fn number(&self) -> DayNumber;
+
+    fn name(&self) -> String {
+        format!("Day {:02}", self.number())
+    }
+
+    fn dir(&self) -> PathBuf {
+        day_dir(self.number())
+    }
+
+    fn default_inputs(&self) -> Vec<String> {
+        default_inputs(self.number())
+    }
+
+    fn params(&self) -> Vec<ParamSpec>;
+
+    fn input_assumptions(&self) -> Vec<InputAssumption>;
+
+    /// Nothing is printed, the report lists the results in the order of `input_files`.
+    fn run(&self, options: &RunOptions, input_files: &[&str]) -> RunReport;
+
+    /// The picture of a single part, `None` if the day cannot draw its input.
+    fn render(
+        &self,
+        part: Part,
+        params: &[ParamArg],
+        input_file: &str,
+    ) -> Result<Option<Canvas>, ParseError>;
+
+    /// Passes the frames of a single part to `frame` and returns the last one,
+    /// `None` if the day cannot animate its solution.
+    fn animate(
+        &self,
+        part: Part,
+        params: &[ParamArg],
+        input_file: &str,
+        frame: &mut dyn FnMut(&Canvas),
+    ) -> Result<Option<Canvas>, ParseError>;
+}
+
+/// All days known to the `aoc` binary, ordered by day.
+#[derive(Default)]
+pub struct Registry {
+    days: Vec<Box<dyn Day>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::default()
+    }
+
+    pub fn register(&mut self, day: impl Day + 'static) {
+        if self.get(day.number()).is_some() {
+            panic!("Day {:02} is registered twice", day.number());
+        }
+
+        self.days.push(Box::new(day));
+        self.days.sort_by_key(|day| day.number());
+    }
+
+    pub fn get(&self, day: DayNumber) -> Option<&dyn Day> {
+        self.days().find(|entry| entry.number() == day)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = &dyn Day> {
+        self.days.iter().map(|day| day.as_ref())
+    }
+
+    /// The days accepted by `predicate`, e.g. to run a range of days in one batch.
+    pub fn filter(&self, predicate: impl Fn(&dyn Day) -> bool) -> Vec<&dyn Day> {
+        self.days().filter(|day| predicate(*day)).collect()
+    }
+}
+
+/// The directory holding the source, inputs and answers of a day, relative to the repository root.
+pub fn day_dir(day: DayNumber) -> PathBuf {
+    PathBuf::from(format!("src/{:02}", day))
+}
+
+/// The `input_N.txt` files of a day ordered by `N`.
+pub fn default_inputs(day: DayNumber) -> Vec<String> {
+    let Ok(entries) = read_dir(day_dir(day)) else {
+        return Vec::new();
+    };
+
+    let mut inputs: Vec<(u32, String)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let file_name = path.file_name()?.to_str()?;
+
+            let number = file_name
+                .strip_prefix("input_")?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()?;
+
+            Some((number, path.to_string_lossy().to_string()))
+        })
+        .collect();
+
+    inputs.sort();
+
+    inputs.into_iter().map(|(_, path)| path).collect()
+}
+
+#[test]
+fn test_default_inputs() {
+    assert!(default_inputs(5)[..2] == ["src/05/input_1.txt", "src/05/input_2.txt"]);
+    assert!(default_inputs(99).is_empty());
+}
+
+#[cfg(test)]
+struct LineCount;
+
+#[cfg(test)]
+impl crate::InputParser<usize> for LineCount {
+    fn parse(&self, source: &crate::Source) -> Result<usize, crate::ParseError> {
+        Ok(source.lines().count())
+    }
+}
+
+#[cfg(test)]
+impl crate::ProblemSolver<usize, usize> for LineCount {
+    fn solve_part_one(&self, lines: &usize, _context: &crate::Context) -> usize {
+        *lines
+    }
+
+    fn solve_part_two(&self, lines: &usize, _context: &crate::Context) -> usize {
+        lines * 2
+    }
+}
+
+#[cfg(test)]
+struct FirstLine;
+
+#[cfg(test)]
+impl crate::InputParser<String> for FirstLine {
+    fn parse(&self, source: &crate::Source) -> Result<String, crate::ParseError> {
+        Ok(source.first_line()?.to_string())
+    }
+}
+
+#[cfg(test)]
+impl crate::ProblemSolver<String, String> for FirstLine {
+    fn solve_part_one(&self, line: &String, _context: &crate::Context) -> String {
+        line.clone()
+    }
+
+    fn solve_part_two(&self, line: &String, _context: &crate::Context) -> String {
+        line.to_uppercase()
+    }
+}
+
+#[test]
+fn test_registry_of_days() {
+    use crate::Runner;
+
+    let mut registry = Registry::new();
+
+    registry.register(Runner::new(5, FirstLine, FirstLine));
+    registry.register(Runner::new(1, LineCount, LineCount));
+
+    let numbers: Vec<DayNumber> = registry.days().map(|day| day.number()).collect();
+
+    assert!(numbers == [1, 5]);
+    assert!(registry.get(5).unwrap().name() == "Day 05");
+    assert!(registry.filter(|day| day.number() > 1).len() == 1);
+
+    let answers: Vec<String> = registry
+        .days()
+        .flat_map(|day| {
+            day.run(&RunOptions::default(), &["src/05/input_1.txt"])
+                .results
+        })
+        .map(|result| result.answer)
+        .collect();
+
+    assert!(answers == ["28", "56", "47|53", "47|53"]);
+}
@@ -0,0 +1,233 @@
+use std::io::{self, IsTerminal, Write};
+
+use crate::{Direction, Grid, Position};
+
+/// The eight basic terminal colors without black and white, which would vanish on some backgrounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    pub const PALETTE: [Color; 6] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+    ];
+
+    /// Cycles through the palette, e.g. to give every region or frequency its own color.
+    pub fn nth(idx: usize) -> Color {
+        Color::PALETTE[idx % Color::PALETTE.len()]
+    }
+
+    /// The color in images, close to the usual terminal colors.
+    pub fn rgb(self) -> [u8; 3] {
+        match self {
+            Color::Red => [205, 49, 49],
+            Color::Green => [13, 188, 121],
+            Color::Yellow => [229, 229, 16],
+            Color::Blue => [36, 114, 200],
+            Color::Magenta => [188, 63, 188],
+            Color::Cyan => [17, 168, 205],
+        }
+    }
+
+    // the foreground code, the background is 10 higher
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// A character with an optional foreground `color` and background `tint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub char: char,
+    pub color: Option<Color>,
+    pub tint: Option<Color>,
+}
+
+impl Cell {
+    fn is_arrow(&self) -> bool {
+        self.char == '+'
+            || Direction::ALL
+                .iter()
+                .any(|direction| direction.to_char() == self.char)
+    }
+}
+
+/// A grid drawn as characters with overlays on top, printed by `aoc show` and saved by `aoc export`.
+/// Colors are left out of the plain text, so the overlays should also change the characters
+/// where they matter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    pub fn new<T>(grid: &Grid<T>, mut char: impl FnMut(&T) -> char) -> Self {
+        Canvas {
+            cells: grid.map(|value| Cell {
+                char: char(value),
+                color: None,
+                tint: None,
+            }),
+        }
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Replaces a cell with all of its colors.
+    pub fn set(&mut self, position: Position, cell: Cell) {
+        self.cells[position] = cell;
+    }
+
+    /// Replaces the character of a cell and colors it.
+    pub fn mark(&mut self, position: Position, char: char, color: Color) {
+        let cell = &mut self.cells[position];
+
+        cell.char = char;
+        cell.color = Some(color);
+    }
+
+    /// Colors a cell but keeps its character.
+    pub fn color(&mut self, position: Position, color: Color) {
+        self.cells[position].color = Some(color);
+    }
+
+    pub fn tint(&mut self, position: Position, color: Color) {
+        self.cells[position].tint = Some(color);
+    }
+
+    /// Draws an arrow for every step, a cell the path crosses in another direction becomes a `+`.
+    pub fn path(&mut self, steps: impl IntoIterator<Item = (Position, Direction)>, color: Color) {
+        for (position, direction) in steps {
+            let cell = &mut self.cells[position];
+            let arrow = direction.to_char();
+
+            cell.char = if cell.color == Some(color) && cell.is_arrow() && cell.char != arrow {
+                '+'
+            } else {
+                arrow
+            };
+            cell.color = Some(color);
+        }
+    }
+
+    /// Writes one line per row, with ANSI escape codes for the colors if `ansi` is set.
+    pub fn write(&self, out: &mut impl Write, ansi: bool) -> io::Result<()> {
+        for row in self.cells.rows() {
+            let mut line = String::new();
+            let mut style = (None, None);
+
+            for cell in row {
+                if ansi && (cell.color, cell.tint) != style {
+                    style = (cell.color, cell.tint);
+                    line.push_str(&escape_code(style));
+                }
+
+                line.push(cell.char);
+            }
+
+            if style != (None, None) {
+                line.push_str(&escape_code((None, None)));
+            }
+
+            writeln!(out, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn escape_code((color, tint): (Option<Color>, Option<Color>)) -> String {
+    // always resets first, so that a cell never inherits the style of the previous one
+    let mut code = String::from("\x1b[0");
+
+    if let Some(color) = color {
+        code.push_str(&format!(";{}", color.ansi_code()));
+    }
+
+    if let Some(tint) = tint {
+        code.push_str(&format!(";{}", tint.ansi_code() + 10));
+    }
+
+    code.push('m');
+    code
+}
+
+/// Colors are only used on a terminal, and never if `NO_COLOR` is set.
+pub fn ansi_supported() -> bool {
+    io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// A small canvas for the tests of the renderers.
+#[cfg(test)]
+pub fn example_canvas() -> Canvas {
+    Canvas::new(
+        &Grid::new(3, 2, false),
+        |wall| if *wall { '#' } else { '.' },
+    )
+}
+
+#[cfg(test)]
+fn render(canvas: &Canvas, ansi: bool) -> String {
+    let mut out = Vec::new();
+
+    canvas.write(&mut out, ansi).unwrap();
+
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_render_plain() {
+    let mut canvas = example_canvas();
+
+    canvas.mark(Position::new(2, 0), 'O', Color::Red);
+    canvas.tint(Position::new(0, 1), Color::Blue);
+
+    assert!(render(&canvas, false) == "..O\n...\n");
+}
+
+#[test]
+fn test_render_ansi() {
+    let mut canvas = example_canvas();
+
+    canvas.color(Position::new(1, 0), Color::Green);
+    canvas.tint(Position::new(1, 0), Color::Blue);
+
+    assert!(render(&canvas, true) == ".\x1b[0;32;44m.\x1b[0m.\n...\n");
+}
+
+#[test]
+fn test_render_path() {
+    let mut canvas = example_canvas();
+
+    canvas.path(
+        [
+            (Position::new(0, 1), Direction::Right),
+            (Position::new(1, 1), Direction::Right),
+            (Position::new(1, 1), Direction::Up),
+            (Position::new(1, 0), Direction::Up),
+        ],
+        Color::Yellow,
+    );
+
+    assert!(render(&canvas, false) == ".^.\n>+.\n");
+}
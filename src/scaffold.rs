@@ -0,0 +1,187 @@
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::Path,
+};
+
+use crate::{
+    registry::{day_dir, DayNumber},
+    ANSWERS_FILE_NAME,
+};
+
+/// The binary's entry point, which declares and registers every day.
+pub const MAIN_FILE: &str = "src/main.rs";
+
+/// Source of a new day: a parser and a solver that only need their bodies filled in.
+pub fn day_module(day: DayNumber) -> String {
+    format!(
+        r#"use aoc_2024::{{Context, InputParser, ParseError, ProblemSolver, Registry, Runner, Source}};
+
+type Input = Vec<String>;
+
+struct Parser;
+
+impl InputParser<Input> for Parser {{
+    fn parse(&self, source: &Source) -> Result<Input, ParseError> {{
+        Ok(source.lines().map(String::from).collect())
+    }}
+}}
+
+type Output = usize;
+
+struct Solver;
+
+impl ProblemSolver<Input, Output> for Solver {{
+    fn solve_part_one(&self, _input: &Input, _context: &Context) -> Output {{
+        0
+    }}
+
+    fn solve_part_two(&self, _input: &Input, _context: &Context) -> Output {{
+        0
+    }}
+}}
+
+pub fn register(registry: &mut Registry) {{
+    registry.register(Runner::new({day}, Parser, Solver));
+}}
+
+aoc_2024::answer_tests!("{day:02}", Parser, Solver);
+"#,
+        day = day
+    )
+}
+
+fn answers_placeholder() -> String {
+    String::from("# file part answer\n# input_1.txt 1 <answer of the example>\n")
+}
+
+/// Inserts `line` before the first line matching `is_after`, or after the last line matching `is_group`.
+fn insert_line(
+    lines: &mut Vec<String>,
+    line: String,
+    is_group: impl Fn(&str) -> bool,
+    is_after: impl Fn(&str) -> bool,
+) -> Result<(), String> {
+    let idx = match lines.iter().position(|line| is_after(line)) {
+        Some(idx) => idx,
+        None => {
+            lines
+                .iter()
+                .rposition(|line| is_group(line))
+                .ok_or_else(|| format!("could not find where to insert {:?}", line.trim()))?
+                + 1
+        }
+    };
+
+    lines.insert(idx, line);
+
+    Ok(())
+}
+
+fn module_day(line: &str) -> Option<DayNumber> {
+    line.strip_prefix("mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+fn path_attribute_day(line: &str) -> Option<DayNumber> {
+    line.strip_prefix("#[path = \"")?
+        .strip_suffix("/mod.rs\"]")?
+        .parse()
+        .ok()
+}
+
+fn register_call_day(line: &str) -> Option<DayNumber> {
+    line.trim()
+        .strip_prefix("day")?
+        .strip_suffix("::register(&mut registry);")?
+        .parse()
+        .ok()
+}
+
+/// Adds the module declaration and the `register` call of `day` to the source of `src/main.rs`, keeping them ordered by day.
+pub fn register_day(main: &str, day: DayNumber) -> Result<String, String> {
+    let mut lines: Vec<String> = main.lines().map(String::from).collect();
+
+    if lines.iter().any(|line| module_day(line) == Some(day)) {
+        return Err(format!(
+            "day {:02} is already declared in {}",
+            day, MAIN_FILE
+        ));
+    }
+
+    // the `#[path]` attribute belongs to the module after it, so a new module goes before the attribute of the next one
+    insert_line(
+        &mut lines,
+        format!("#[path = \"{:02}/mod.rs\"]\nmod day{:02};", day, day),
+        |line| module_day(line).is_some(),
+        |line| path_attribute_day(line).is_some_and(|other| other > day),
+    )?;
+
+    insert_line(
+        &mut lines,
+        format!("    day{:02}::register(&mut registry);", day),
+        |line| register_call_day(line).is_some(),
+        |line| register_call_day(line).is_some_and(|other| other > day),
+    )?;
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the module, a placeholder input and answers file of `day` and registers it in `src/main.rs`.
+/// Returns the paths of the files that were created or changed.
+pub fn create_day(day: DayNumber) -> Result<Vec<String>, String> {
+    let dir = day_dir(day);
+    let module_file = dir.join("mod.rs");
+
+    if module_file.exists() {
+        return Err(format!("{} already exists", module_file.display()));
+    }
+
+    let main = read_to_string(MAIN_FILE).map_err(|error| format!("{}: {}", MAIN_FILE, error))?;
+    let main = register_day(&main, day)?;
+
+    create_dir_all(&dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+
+    let mut changed = Vec::new();
+
+    let mut create = |path: &Path, content: String, overwrite: bool| {
+        if !overwrite && path.exists() {
+            return Ok(());
+        }
+
+        write(path, content).map_err(|error| format!("{}: {}", path.display(), error))?;
+        changed.push(path.to_string_lossy().to_string());
+
+        Ok::<(), String>(())
+    };
+
+    create(&module_file, day_module(day), false)?;
+    // the example input goes into `input_1.txt`, a fetched input may already be there
+    create(&dir.join("input_1.txt"), String::new(), false)?;
+    create(&dir.join(ANSWERS_FILE_NAME), answers_placeholder(), false)?;
+    create(Path::new(MAIN_FILE), main, true)?;
+
+    Ok(changed)
+}
+
+#[test]
+fn test_register_day() {
+    let main = "#[path = \"01/mod.rs\"]\nmod day01;\n#[path = \"03/mod.rs\"]\nmod day03;\n\nfn registry() -> Registry {\n    let mut registry = Registry::new();\n\n    day01::register(&mut registry);\n    day03::register(&mut registry);\n\n    registry\n}\n";
+
+    let registered = register_day(main, 2).unwrap();
+
+    assert!(registered.contains(
+        "mod day01;\n#[path = \"02/mod.rs\"]\nmod day02;\n#[path = \"03/mod.rs\"]\nmod day03;\n"
+    ));
+    assert!(registered.contains(
+        "    day01::register(&mut registry);\n    day02::register(&mut registry);\n    day03::register(&mut registry);\n"
+    ));
+
+    let registered = register_day(main, 13).unwrap();
+
+    assert!(registered.contains("mod day03;\n#[path = \"13/mod.rs\"]\nmod day13;\n\nfn"));
+    assert!(registered.contains("    day13::register(&mut registry);\n\n    registry"));
+
+    assert!(register_day(main, 3).is_err());
+}
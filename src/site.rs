@@ -0,0 +1,253 @@
+use std::{
+    env,
+    fs::{create_dir_all, read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    registry::{day_dir, default_inputs, DayNumber},
+    Part,
+};
+
+pub const YEAR: u32 = 2024;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides the default base URL, e.g. to point the tool at a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// Holds the value of the `session` cookie of a logged in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Downloaded inputs, relative to the repository root, so that they are never downloaded twice.
+pub const CACHE_DIR: &str = ".aoc/cache";
+
+const USER_AGENT: &str = concat!("aoc-2024/", env!("CARGO_PKG_VERSION"));
+
+/// The Advent of Code website, or anything answering to the same URLs.
+pub struct Site {
+    base_url: String,
+    session: String,
+}
+
+impl Site {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Site {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Reads the session token and the base URL from the environment, `base_url` takes precedence over the latter.
+    pub fn from_env(base_url: Option<&str>) -> Result<Self, String> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or_else(|| format!("{} is not set", SESSION_VAR))?;
+
+        let base_url = match base_url {
+            Some(base_url) => base_url.to_string(),
+            None => env::var(BASE_URL_VAR).unwrap_or_else(|_| String::from(DEFAULT_BASE_URL)),
+        };
+
+        Ok(Site::new(&base_url, &session))
+    }
+
+    pub fn day_url(&self, day: DayNumber) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    fn get(&self, url: &str) -> Result<String, String> {
+        let mut response = ureq::get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|error| request_error(url, error))?;
+
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|error| format!("{}: {}", url, error))
+    }
+
+    /// Posts an answer, returning the page of the response.
+    pub fn submit_answer(
+        &self,
+        day: DayNumber,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, String> {
+        let url = format!("{}/answer", self.day_url(day));
+
+        let mut response = ureq::post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])
+            .map_err(|error| request_error(&url, error))?;
+
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|error| format!("{}: {}", url, error))
+    }
+
+    /// The personal input of a day, downloaded only if it is not in `cache_dir` yet.
+    /// Returns the path of the cached input and whether it was downloaded.
+    pub fn fetch_input(&self, day: DayNumber, cache_dir: &Path) -> Result<(PathBuf, bool), String> {
+        let cached = cached_input(day, cache_dir);
+
+        if cached.exists() {
+            return Ok((cached, false));
+        }
+
+        let input = self.get(&format!("{}/input", self.day_url(day)))?;
+
+        if let Some(dir) = cached.parent() {
+            create_dir_all(dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+        }
+
+        write(&cached, input).map_err(|error| format!("{}: {}", cached.display(), error))?;
+
+        Ok((cached, true))
+    }
+}
+
+/// Where `Site::fetch_input` stores the personal input of a day.
+pub fn cached_input(day: DayNumber, cache_dir: &Path) -> PathBuf {
+    cache_dir
+        .join(YEAR.to_string())
+        .join(format!("input_{:02}.txt", day))
+}
+
+fn request_error(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::StatusCode(404) => {
+            format!("{}: not found, the puzzle may not be unlocked yet", url)
+        }
+        ureq::Error::StatusCode(400) | ureq::Error::StatusCode(500) => {
+            format!("{}: request rejected, check {}", url, SESSION_VAR)
+        }
+        error => format!("{}: {}", url, error),
+    }
+}
+
+/// Copies a cached input to the next free `input_N.txt` of the day, unless one of them already has the same content.
+/// Returns the path of the day's input and whether it was added.
+pub fn install_input(day: DayNumber, cached: &Path) -> Result<(String, bool), String> {
+    let input =
+        read_to_string(cached).map_err(|error| format!("{}: {}", cached.display(), error))?;
+
+    let existing = default_inputs(day);
+
+    if let Some(path) = existing
+        .iter()
+        .find(|path| read_to_string(path).is_ok_and(|text| text == input))
+    {
+        return Ok((path.clone(), false));
+    }
+
+    let dir = day_dir(day);
+    create_dir_all(&dir).map_err(|error| format!("{}: {}", dir.display(), error))?;
+
+    let path = (1..)
+        .map(|idx| dir.join(format!("input_{}.txt", idx)))
+        .find(|path| !path.exists())
+        .unwrap();
+
+    write(&path, input).map_err(|error| format!("{}: {}", path.display(), error))?;
+
+    Ok((path.to_string_lossy().to_string(), true))
+}
+
+/// Serves `body` with `status` to every request until the test process ends, returning its base URL
+/// and a receiver of the raw requests.
+#[cfg(test)]
+pub fn stub_server(status: u16, body: &'static str) -> (String, std::sync::mpsc::Receiver<String>) {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+
+            // the requests are small, reading until the end of the headers and the declared body is enough
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+
+                let text = String::from_utf8_lossy(&request);
+
+                if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                    let content_length = headers
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length: ")
+                                .map(String::from)
+                        })
+                        .and_then(|length| length.trim().parse().ok())
+                        .unwrap_or(0);
+
+                    if body.len() >= content_length {
+                        break;
+                    }
+                }
+
+                if read == 0 {
+                    break;
+                }
+            }
+
+            let _ = sender.send(String::from_utf8_lossy(&request).to_string());
+
+            write!(
+                stream,
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+        }
+    });
+
+    (base_url, receiver)
+}
+
+#[test]
+fn test_fetch_input() {
+    let (base_url, requests) = stub_server(200, "1 2\n3 4\n");
+    let site = Site::new(&base_url, "secret\n");
+    let cache_dir = crate::testing::temp_dir("fetch");
+
+    let (cached, downloaded) = site.fetch_input(7, &cache_dir).unwrap();
+
+    assert!(downloaded);
+    assert!(read_to_string(&cached).unwrap() == "1 2\n3 4\n");
+
+    let request = requests.recv().unwrap();
+
+    assert!(request.starts_with("GET /2024/day/7/input HTTP/1.1\r\n"));
+    assert!(request.contains("session=secret\r\n"));
+
+    // the second fetch is served from the cache
+    assert!(site.fetch_input(7, &cache_dir).unwrap() == (cached, false));
+    assert!(requests.try_recv().is_err());
+}
+
+#[test]
+fn test_fetch_missing_input() {
+    let (base_url, _requests) = stub_server(404, "Not Found");
+    let site = Site::new(&base_url, "secret");
+
+    let error = site
+        .fetch_input(25, &crate::testing::temp_dir("missing"))
+        .unwrap_err();
+
+    assert!(error.contains("not be unlocked yet"));
+}
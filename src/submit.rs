@@ -0,0 +1,398 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+
+use crate::{
+    records::{append_records, load_records, parse_records},
+    registry::DayNumber,
+    ParseError, Part, Source,
+};
+
+/// Every submitted answer, relative to the repository root.
+pub const LEDGER_FILE: &str = ".aoc/submissions.txt";
+
+const LEDGER_HEADER: &str = "# timestamp\tday\tpart\toutcome\twait_secs\tanswer";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    // wrong without a hint
+    Wrong,
+    // submitted during the cooldown, the answer was not checked
+    TooSoon,
+    // the part is already solved or not unlocked yet
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::TooSoon => "too_soon",
+            Outcome::WrongLevel => "wrong_level",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooSoon => write!(f, "submitted too soon"),
+            Outcome::WrongLevel => write!(f, "already solved or locked"),
+            Outcome::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        [
+            Outcome::Correct,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wrong,
+            Outcome::TooSoon,
+            Outcome::WrongLevel,
+            Outcome::Unknown,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.name() == value)
+        .ok_or_else(|| format!("invalid outcome {:?}", value))
+    }
+}
+
+/// Reads the outcome and the cooldown, if any, from the page returned for a submitted answer.
+pub fn parse_response(html: &str) -> (Outcome, Option<Duration>) {
+    let outcome = if html.contains("That's the right answer") {
+        Outcome::Correct
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if html.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if html.contains("You gave an answer too recently") {
+        Outcome::TooSoon
+    } else if html.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown
+    };
+
+    // "You have 1m 5s left to wait" after submitting too soon,
+    // "please wait 5 minutes before trying again" after a wrong answer
+    let left_to_wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    let wait_minutes =
+        Regex::new(r"[Pp]lease wait (one|\d+) minutes? before trying again").unwrap();
+
+    let wait = if let Some(captures) = left_to_wait.captures(html) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+
+        Some(Duration::from_secs(minutes * 60 + seconds))
+    } else {
+        wait_minutes.captures(html).map(|captures| {
+            let minutes = match &captures[1] {
+                "one" => 1,
+                minutes => minutes.parse().unwrap(),
+            };
+
+            Duration::from_secs(minutes * 60)
+        })
+    };
+
+    (outcome, wait)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    // seconds since the unix epoch
+    pub timestamp: u64,
+    pub day: DayNumber,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub wait: Option<Duration>,
+    pub answer: String,
+}
+
+impl Attempt {
+    fn parse(source: &Source, line: &str) -> Result<Self, ParseError> {
+        let fields: Vec<&str> = line.splitn(6, '\t').collect();
+
+        let [timestamp, day, part, outcome, wait, answer] = fields[..] else {
+            return Err(source.error(line, "expected 6 tab separated fields"));
+        };
+
+        Ok(Attempt {
+            timestamp: source.parse(timestamp)?,
+            day: source.parse(day)?,
+            part: source.parse(part)?,
+            outcome: source.parse(outcome)?,
+            wait: match wait {
+                "" => None,
+                wait => Some(Duration::from_secs(source.parse(wait)?)),
+            },
+            answer: answer.to_string(),
+        })
+    }
+
+    /// When the next answer can be submitted.
+    fn not_before(&self) -> u64 {
+        self.timestamp + self.wait.map_or(0, |wait| wait.as_secs())
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.day,
+            self.part,
+            self.outcome.name(),
+            self.wait
+                .map_or(String::new(), |wait| wait.as_secs().to_string()),
+            self.answer
+        )
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// All attempts submitted so far, used to refuse answers that cannot be right.
+#[derive(Debug, Default)]
+pub struct Ledger {
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    pub fn parse(source: &Source) -> Result<Self, ParseError> {
+        Ok(Ledger {
+            attempts: parse_records(source, Attempt::parse)?,
+        })
+    }
+
+    /// Loads the ledger, a missing ledger file is the same as an empty one.
+    pub fn load(ledger_file: &str) -> Result<Self, ParseError> {
+        Ok(Ledger {
+            attempts: load_records(ledger_file, Attempt::parse)?,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Returns why `answer` should not be submitted, if it is known to be wrong or the cooldown is not over yet.
+    pub fn check(&self, day: DayNumber, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        let attempts: Vec<&Attempt> = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part)
+            .collect();
+
+        if let Some(correct) = attempts
+            .iter()
+            .find(|attempt| attempt.outcome == Outcome::Correct)
+        {
+            return Err(format!("already solved with {}", correct.answer));
+        }
+
+        if let Some(wrong) = attempts
+            .iter()
+            .find(|attempt| attempt.outcome.is_wrong() && attempt.answer == answer)
+        {
+            return Err(format!(
+                "{} was already submitted, {}",
+                answer, wrong.outcome
+            ));
+        }
+
+        // the hints only apply to numbers, but almost all answers are
+        if let Ok(value) = answer.parse::<i128>() {
+            for attempt in attempts.iter() {
+                let Ok(submitted) = attempt.answer.parse::<i128>() else {
+                    continue;
+                };
+
+                match attempt.outcome {
+                    Outcome::TooHigh if value >= submitted => {
+                        return Err(format!(
+                            "{} was too high, {} is not lower",
+                            submitted, answer
+                        ))
+                    }
+                    Outcome::TooLow if value <= submitted => {
+                        return Err(format!(
+                            "{} was too low, {} is not higher",
+                            submitted, answer
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(not_before) = attempts.iter().map(|attempt| attempt.not_before()).max() {
+            if not_before > now {
+                return Err(format!(
+                    "wait {}s before submitting again",
+                    not_before - now
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, ledger_file: &str, attempt: Attempt) -> std::io::Result<()> {
+        append_records(ledger_file, LEDGER_HEADER, std::slice::from_ref(&attempt))?;
+
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse_response() {
+    assert!(
+        parse_response("<p>That's the right answer! You are one gold star closer.</p>")
+            == (Outcome::Correct, None)
+    );
+    assert!(
+        parse_response("<p>That's not the right answer; your answer is too low. Please wait one minute before trying again.</p>")
+            == (Outcome::TooLow, Some(Duration::from_secs(60)))
+    );
+    assert!(
+        parse_response("<p>You gave an answer too recently. You have 2m 5s left to wait.</p>")
+            == (Outcome::TooSoon, Some(Duration::from_secs(125)))
+    );
+    assert!(
+        parse_response("<p>You don't seem to be solving the right level.</p>").0
+            == Outcome::WrongLevel
+    );
+}
+
+#[test]
+fn test_ledger_check() {
+    let too_high = Attempt {
+        timestamp: 1000,
+        day: 6,
+        part: Part::Two,
+        outcome: Outcome::TooHigh,
+        wait: Some(Duration::from_secs(60)),
+        answer: String::from("1500"),
+    };
+    let too_low = Attempt {
+        outcome: Outcome::TooLow,
+        wait: None,
+        answer: String::from("900"),
+        ..too_high.clone()
+    };
+    let wrong = Attempt {
+        outcome: Outcome::Wrong,
+        answer: String::from("1200"),
+        ..too_low.clone()
+    };
+    let correct = Attempt {
+        outcome: Outcome::Correct,
+        answer: String::from("1100"),
+        ..too_low.clone()
+    };
+
+    let mut ledger = Ledger {
+        attempts: vec![too_high, too_low, wrong],
+    };
+
+    assert!(ledger.check(6, Part::Two, "1000", 1030).is_err());
+    assert!(ledger.check(6, Part::Two, "1000", 1060).is_ok());
+    assert!(ledger.check(6, Part::Two, "1500", 2000).is_err());
+    assert!(ledger.check(6, Part::Two, "1600", 2000).is_err());
+    assert!(ledger.check(6, Part::Two, "800", 2000).is_err());
+    assert!(ledger.check(6, Part::Two, "1200", 2000).is_err());
+    assert!(ledger.check(6, Part::One, "1500", 2000).is_ok());
+
+    ledger.attempts.push(correct);
+
+    assert!(ledger.check(6, Part::Two, "1000", 2000).is_err());
+}
+
+#[test]
+fn test_parse_ledger() {
+    let too_high = Attempt {
+        timestamp: 1000,
+        day: 6,
+        part: Part::Two,
+        outcome: Outcome::TooHigh,
+        wait: Some(Duration::from_secs(60)),
+        answer: String::from("1500"),
+    };
+    let attempts = vec![
+        too_high.clone(),
+        Attempt {
+            outcome: Outcome::Correct,
+            wait: None,
+            answer: String::from("a b"),
+            ..too_high
+        },
+    ];
+    let text: String = attempts
+        .iter()
+        .map(|attempt| format!("{}\n", attempt))
+        .collect();
+
+    let ledger = Ledger::parse(&Source::new(
+        LEDGER_FILE,
+        &format!("{}\n{}", LEDGER_HEADER, text),
+    ))
+    .unwrap();
+
+    assert!(ledger.attempts() == attempts);
+}
+
+#[test]
+fn test_submit_answer() {
+    let (base_url, requests) = crate::site::stub_server(
+        200,
+        "<article><p>That's not the right answer; your answer is too high. Please wait 5 minutes before trying again.</p></article>",
+    );
+    let site = crate::site::Site::new(&base_url, "secret");
+
+    let response = site.submit_answer(6, Part::Two, "1500").unwrap();
+
+    assert!(parse_response(&response) == (Outcome::TooHigh, Some(Duration::from_secs(300))));
+
+    let request = requests.recv().unwrap();
+
+    assert!(request.starts_with("POST /2024/day/6/answer HTTP/1.1\r\n"));
+    assert!(request.ends_with("level=2&answer=1500"));
+}
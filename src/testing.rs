@@ -0,0 +1,11 @@
+use std::{env, fs::remove_dir_all, path::PathBuf};
+
+/// A directory of its own for a test, removed first if an earlier run left it behind.
+/// It is not created, so tests can also use it as a missing path.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-2024-{}-{}", name, std::process::id()));
+
+    let _ = remove_dir_all(&dir);
+
+    dir
+}
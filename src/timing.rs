@@ -0,0 +1,88 @@
+use std::time::{Duration, Instant};
+
+/// Durations of repeated runs of the same step.
+#[derive(Debug, Clone, Default)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    /// Runs `step` `repeat` times (at least once), returning the last result with the durations of all runs.
+    pub fn measure<T>(repeat: usize, mut step: impl FnMut() -> T) -> (T, Timings) {
+        let mut samples = Vec::with_capacity(repeat);
+
+        let mut run = || {
+            let start = Instant::now();
+            let result = step();
+            samples.push(start.elapsed());
+
+            result
+        };
+
+        let mut result = run();
+
+        for _ in 1..repeat {
+            result = run();
+        }
+
+        (result, Timings { samples })
+    }
+
+    pub fn from_samples(samples: Vec<Duration>) -> Self {
+        Timings { samples }
+    }
+
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+
+        match sorted.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 1 => sorted[len / 2],
+            len => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+#[test]
+fn test_median() {
+    let timings = Timings::from_samples(vec![
+        Duration::from_millis(4),
+        Duration::from_millis(1),
+        Duration::from_millis(3),
+        Duration::from_millis(2),
+    ]);
+
+    assert!(timings.min() == Duration::from_millis(1));
+    assert!(timings.median() == Duration::from_micros(2500));
+    assert!(timings.max() == Duration::from_millis(4));
+}
+
+#[test]
+fn test_measure_repeats() {
+    let mut runs = 0;
+
+    let (result, timings) = Timings::measure(3, || {
+        runs += 1;
+        runs
+    });
+
+    assert!(result == 3);
+    assert!(timings.samples().len() == 3);
+}
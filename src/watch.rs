@@ -0,0 +1,173 @@
+use std::{
+    collections::BTreeMap,
+    fs::read_dir,
+    path::PathBuf,
+    process::{Command, ExitCode, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    output::{parse_csv_record, CSV_HEADER},
+    registry::{day_dir, DayNumber},
+};
+
+/// How often the day's directory is checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Modification times of every file in the day's directory: its source, inputs and answers.
+fn snapshot(day: DayNumber) -> Snapshot {
+    let Ok(entries) = read_dir(day_dir(day)) else {
+        return Snapshot::new();
+    };
+
+    entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let metadata = entry.metadata().ok()?;
+
+            if !metadata.is_file() {
+                return None;
+            }
+
+            Some((entry.path(), metadata.modified().ok()?))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WatchedResult {
+    answer: String,
+    status: String,
+}
+
+// keyed by input file and part, errors use an empty part
+type Results = BTreeMap<(String, String), WatchedResult>;
+
+fn parse_results(csv: &str) -> Option<Results> {
+    let mut lines = csv.lines();
+
+    if lines.next()? != CSV_HEADER {
+        return None;
+    }
+
+    let results = lines
+        .filter_map(|line| {
+            let fields = parse_csv_record(line);
+
+            let [_, part, file, answer, _, status, ..] = fields.as_slice() else {
+                return None;
+            };
+
+            let answer = match fields.last() {
+                Some(message) if status == "error" => message.clone(),
+                _ => answer.clone(),
+            };
+
+            Some((
+                (file.clone(), part.clone()),
+                WatchedResult {
+                    answer,
+                    status: status.clone(),
+                },
+            ))
+        })
+        .collect();
+
+    Some(results)
+}
+
+/// Rebuilds and runs the day in a child process, the binary itself cannot pick up source changes.
+fn run_day(day: DayNumber, args: &[String]) -> Option<Results> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| String::from("cargo"));
+
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet"]);
+
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    command
+        .args(["--bin", "aoc", "--", "run", &day.to_string()])
+        .args(args)
+        .args(["--format", "csv"])
+        .stderr(Stdio::inherit());
+
+    let output = command.output().ok()?;
+
+    parse_results(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn print_diff(previous: Option<&Results>, current: &Results) {
+    for ((file, part), result) in current.iter() {
+        let change = match previous.map(|previous| previous.get(&(file.clone(), part.clone()))) {
+            None => String::new(),
+            Some(None) => String::from(", new"),
+            Some(Some(old)) if old == result => String::from(", unchanged"),
+            Some(Some(old)) => format!(", was {} ({})", old.answer, old.status),
+        };
+
+        if part.is_empty() {
+            println!(
+                "File {}: {} ({}{})",
+                file, result.answer, result.status, change
+            );
+        } else {
+            println!(
+                "File {}, part {}: {} ({}{})",
+                file, part, result.answer, result.status, change
+            );
+        }
+    }
+
+    for (file, part) in previous.into_iter().flat_map(|previous| previous.keys()) {
+        if !current.contains_key(&(file.clone(), part.clone())) {
+            println!("File {}, part {}: gone", file, part);
+        }
+    }
+}
+
+/// Runs the day whenever one of the files in its directory changes, until interrupted.
+/// `args` are passed on to `aoc run`.
+pub fn watch(day: DayNumber, args: &[String]) -> ExitCode {
+    let mut previous: Option<Results> = None;
+
+    loop {
+        let before = snapshot(day);
+
+        println!("Day {:02}", day);
+
+        match run_day(day, args) {
+            Some(results) => {
+                print_diff(previous.as_ref(), &results);
+                previous = Some(results);
+            }
+            None => println!("Build or run failed"),
+        }
+
+        println!("Waiting for changes in {}", day_dir(day).display());
+
+        while snapshot(day) == before {
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        println!();
+    }
+}
+
+#[test]
+fn test_parse_results() {
+    let csv = format!(
+        "{}\n1,1,src/01/input_1.txt,11,11,pass,1,1,1,1,1,1,,,,,,,\n1,,src/01/input_2.txt,,,error,,,,,,,,,,,,,\"bad, input\"\n",
+        CSV_HEADER
+    );
+
+    let results = parse_results(&csv).unwrap();
+
+    assert!(results[&(String::from("src/01/input_1.txt"), String::from("1"))].answer == "11");
+    assert!(results[&(String::from("src/01/input_2.txt"), String::new())].answer == "bad, input");
+    assert!(parse_results("").is_none());
+}